@@ -3,6 +3,15 @@ use crate::services::AgentService;
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+/// Append a "did you mean: ..." hint built from `suggestions` to `message`.
+fn did_you_mean(message: String, suggestions: &[String]) -> String {
+    if suggestions.is_empty() {
+        message
+    } else {
+        format!("{} (did you mean: {}?)", message, suggestions.join(", "))
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct AgentLibraryResponse {
     pub version: String,
@@ -11,11 +20,12 @@ pub struct AgentLibraryResponse {
 }
 
 #[tauri::command]
-pub fn get_agent_library() -> Result<AgentLibraryResponse, String> {
+pub fn get_agent_library(project_path: Option<String>) -> Result<AgentLibraryResponse, String> {
     let agent_service = AgentService::new();
+    let path_buf = project_path.map(PathBuf::from);
 
     let library = agent_service
-        .get_embedded_library()
+        .get_library(path_buf.as_deref())
         .map_err(|e| e.to_string())?;
 
     Ok(AgentLibraryResponse {
@@ -26,29 +36,54 @@ pub fn get_agent_library() -> Result<AgentLibraryResponse, String> {
 }
 
 #[tauri::command]
-pub fn get_agents_by_category(category: String) -> Result<Vec<AgentTemplate>, String> {
+pub fn get_agents_by_category(
+    category: String,
+    project_path: Option<String>,
+) -> Result<Vec<AgentTemplate>, String> {
     let agent_service = AgentService::new();
+    let path_buf = project_path.map(PathBuf::from);
 
-    agent_service
-        .get_agents_by_category(&category)
-        .map_err(|e| e.to_string())
+    let agents = agent_service
+        .get_agents_by_category(&category, path_buf.as_deref())
+        .map_err(|e| e.to_string())?;
+
+    if agents.is_empty() {
+        let suggestions = agent_service
+            .suggest_similar_categories(&category, path_buf.as_deref())
+            .map_err(|e| e.to_string())?;
+
+        return Err(did_you_mean(
+            format!("No agents found in category: {}", category),
+            &suggestions,
+        ));
+    }
+
+    Ok(agents)
 }
 
 #[tauri::command]
-pub fn search_agents(keyword: String) -> Result<Vec<AgentTemplate>, String> {
+pub fn search_agents(
+    keyword: String,
+    project_path: Option<String>,
+) -> Result<Vec<AgentTemplate>, String> {
     let agent_service = AgentService::new();
+    let path_buf = project_path.map(PathBuf::from);
 
     agent_service
-        .search_agents(&keyword)
+        .search_agents(&keyword, path_buf.as_deref())
         .map_err(|e| e.to_string())
 }
 
 #[tauri::command]
-pub fn get_agent_by_id(id: String) -> Result<Option<AgentTemplate>, String> {
+pub fn get_agent_by_id(
+    id: String,
+    project_path: Option<String>,
+) -> Result<Option<AgentTemplate>, String> {
     let agent_service = AgentService::new();
+    let path_buf = project_path.map(PathBuf::from);
 
     agent_service
-        .get_agent_by_id(&id)
+        .get_agent_by_id(&id, path_buf.as_deref())
         .map_err(|e| e.to_string())
 }
 
@@ -56,13 +91,27 @@ pub fn get_agent_by_id(id: String) -> Result<Option<AgentTemplate>, String> {
 pub fn generate_agent_file(
     agent_id: String,
     custom_instructions: Option<String>,
+    project_path: Option<String>,
 ) -> Result<String, String> {
     let agent_service = AgentService::new();
+    let path_buf = project_path.map(PathBuf::from);
 
-    let agent = agent_service
-        .get_agent_by_id(&agent_id)
+    let agent = match agent_service
+        .get_agent_by_id(&agent_id, path_buf.as_deref())
         .map_err(|e| e.to_string())?
-        .ok_or_else(|| format!("Agent not found: {}", agent_id))?;
+    {
+        Some(agent) => agent,
+        None => {
+            let suggestions = agent_service
+                .suggest_similar_ids(&agent_id, path_buf.as_deref())
+                .map_err(|e| e.to_string())?;
+
+            return Err(did_you_mean(
+                format!("Agent not found: {}", agent_id),
+                &suggestions,
+            ));
+        }
+    };
 
     Ok(agent_service.generate_agent_file(&agent, custom_instructions.as_deref()))
 }
@@ -82,10 +131,38 @@ pub fn save_agent_file(
 }
 
 #[tauri::command]
-pub fn get_suggested_agents(technologies: Vec<String>) -> Result<Vec<AgentTemplate>, String> {
+pub fn get_suggested_agents(
+    technologies: Vec<String>,
+    project_path: Option<String>,
+) -> Result<Vec<AgentTemplate>, String> {
+    let agent_service = AgentService::new();
+    let path_buf = project_path.map(PathBuf::from);
+
+    agent_service
+        .get_suggested_agents_for_technologies(&technologies, path_buf.as_deref())
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn save_agent_template(
+    agent: AgentTemplate,
+    project_path: String,
+) -> Result<String, String> {
+    let agent_service = AgentService::new();
+    let path_buf = PathBuf::from(&project_path);
+
+    agent_service
+        .save_agent_template(&agent, &path_buf)
+        .map(|_| format!("Saved custom agent '{}'", agent.id))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn list_custom_agents(project_path: String) -> Result<Vec<AgentTemplate>, String> {
     let agent_service = AgentService::new();
+    let path_buf = PathBuf::from(&project_path);
 
     agent_service
-        .get_suggested_agents_for_technologies(&technologies)
+        .list_custom_agents(&path_buf)
         .map_err(|e| e.to_string())
 }
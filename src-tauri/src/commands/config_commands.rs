@@ -1,4 +1,4 @@
-use crate::models::TeamForgeConfig;
+use crate::models::{ForgeConfig, TeamForgeConfig};
 use crate::services::ConfigService;
 use std::path::PathBuf;
 
@@ -71,6 +71,35 @@ pub fn initialize_teamforge(project_path: String) -> Result<String, String> {
         .map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub fn save_forge_config(forge_config: ForgeConfig) -> Result<String, String> {
+    let config_service = ConfigService::new();
+
+    config_service
+        .save_forge_config(&forge_config)
+        .map(|_| format!("Saved {} forge config", forge_config.forge))
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn load_forge_config(forge: String) -> Result<Option<ForgeConfig>, String> {
+    let config_service = ConfigService::new();
+
+    config_service
+        .load_forge_config(&forge)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn migrate_config(project_path: String) -> Result<TeamForgeConfig, String> {
+    let config_service = ConfigService::new();
+    let path_buf = PathBuf::from(&project_path);
+
+    config_service
+        .migrate_config(&path_buf)
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub fn ensure_claude_agents_dir(project_path: String) -> Result<String, String> {
     let config_service = ConfigService::new();
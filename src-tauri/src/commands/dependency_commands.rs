@@ -0,0 +1,19 @@
+use crate::services::parser_service::DependencyCheckReport;
+use crate::services::ParserService;
+use std::path::PathBuf;
+
+/// Check pinned dependency versions in the manifest at `manifest_path`
+/// against their upstream registry, reporting both the outdated
+/// dependencies and any that couldn't be checked.
+#[tauri::command]
+pub async fn check_outdated_dependencies(
+    manifest_path: String,
+) -> Result<DependencyCheckReport, String> {
+    let parser = ParserService::new();
+    let path_buf = PathBuf::from(&manifest_path);
+
+    parser
+        .check_outdated(&path_buf)
+        .await
+        .map_err(|e| e.to_string())
+}
@@ -1,14 +1,77 @@
-use crate::services::GitService;
+use crate::services::forge::{parse_shorthand, provider_for, ForgeCredentials};
+use crate::services::git_service::{CommitSummary, RepoStatus};
+use crate::services::{ConfigService, GitService};
+use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 
+#[derive(Debug, Serialize, Deserialize)]
+pub struct CloneResult {
+    pub message: String,
+    /// Forge-reported language breakdown, as (language, percentage) pairs,
+    /// populated only when `url` used a `forge:owner/repo` shorthand.
+    pub language_breakdown: Vec<(String, f64)>,
+}
+
+/// Clone a repository. `url` may be a plain git URL, or a `forge:owner/repo`
+/// shorthand (e.g. `github:owner/repo`) that's resolved against a
+/// compiled-in `ForgeProvider`, pre-seeding the result with the forge's
+/// reported language stats before any local manifest scan runs.
 #[tauri::command]
-pub fn clone_repo(url: String, path: String) -> Result<String, String> {
+pub async fn clone_repo(url: String, path: String) -> Result<CloneResult, String> {
     let git_service = GitService::new();
     let path_buf = PathBuf::from(&path);
 
+    let Some((forge, shorthand)) = parse_shorthand(&url) else {
+        return git_service
+            .clone_repository(&url, &path_buf)
+            .map(|_| CloneResult {
+                message: format!("Successfully cloned repository to {}", path),
+                language_breakdown: Vec::new(),
+            })
+            .map_err(|e| e.to_string());
+    };
+
+    let config_service = ConfigService::new();
+    let forge_config = config_service
+        .load_forge_config(forge)
+        .map_err(|e| e.to_string())?;
+
+    let credentials = ForgeCredentials {
+        endpoint: forge_config.as_ref().and_then(|c| c.endpoint.clone()),
+        token: forge_config
+            .as_ref()
+            .and_then(|c| c.auth_env_var.as_ref())
+            .and_then(|var| std::env::var(var).ok()),
+    };
+
+    let provider = provider_for(forge, credentials).map_err(|e| e.to_string())?;
+    let clone_url = provider
+        .resolve_clone_url(shorthand)
+        .map_err(|e| e.to_string())?;
+
     git_service
-        .clone_repository(&url, &path_buf)
-        .map(|_| format!("Successfully cloned repository to {}", path))
+        .clone_repository(&clone_url, &path_buf)
+        .map_err(|e| e.to_string())?;
+
+    let language_breakdown = provider
+        .language_breakdown(shorthand)
+        .await
+        .unwrap_or_default();
+
+    Ok(CloneResult {
+        message: format!("Successfully cloned {} to {}", shorthand, path),
+        language_breakdown,
+    })
+}
+
+#[tauri::command]
+pub fn clone_repo_if_missing(url: String, path: String) -> Result<String, String> {
+    let git_service = GitService::new();
+    let path_buf = PathBuf::from(&path);
+
+    git_service
+        .clone_if_missing(&url, &path_buf)
+        .map(|_| format!("Repository ready at {}", path))
         .map_err(|e| e.to_string())
 }
 
@@ -32,6 +95,58 @@ pub fn get_repo_status(path: String) -> Result<Vec<String>, String> {
     git_service.get_status(&repo).map_err(|e| e.to_string())
 }
 
+#[tauri::command]
+pub fn get_repo_status_detailed(path: String) -> Result<RepoStatus, String> {
+    let git_service = GitService::new();
+    let path_buf = PathBuf::from(&path);
+
+    let repo = git_service
+        .open_repository(&path_buf)
+        .map_err(|e| e.to_string())?;
+
+    git_service
+        .get_structured_status(&repo)
+        .map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn list_git_branches(path: String) -> Result<Vec<String>, String> {
+    let git_service = GitService::new();
+    let path_buf = PathBuf::from(&path);
+
+    let repo = git_service
+        .open_repository(&path_buf)
+        .map_err(|e| e.to_string())?;
+
+    git_service.list_branches(&repo).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn list_git_tags(path: String) -> Result<Vec<String>, String> {
+    let git_service = GitService::new();
+    let path_buf = PathBuf::from(&path);
+
+    let repo = git_service
+        .open_repository(&path_buf)
+        .map_err(|e| e.to_string())?;
+
+    git_service.list_tags(&repo).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn get_recent_commits(path: String, limit: usize) -> Result<Vec<CommitSummary>, String> {
+    let git_service = GitService::new();
+    let path_buf = PathBuf::from(&path);
+
+    let repo = git_service
+        .open_repository(&path_buf)
+        .map_err(|e| e.to_string())?;
+
+    git_service
+        .recent_commits(&repo, limit)
+        .map_err(|e| e.to_string())
+}
+
 #[tauri::command]
 pub fn create_git_commit(
     path: String,
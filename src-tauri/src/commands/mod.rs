@@ -2,12 +2,14 @@
 
 mod agent_commands;
 mod config_commands;
+mod dependency_commands;
 mod git_commands;
 mod project_commands;
 
 // Re-export all commands
 pub use agent_commands::*;
 pub use config_commands::*;
+pub use dependency_commands::*;
 pub use git_commands::*;
 pub use project_commands::*;
 
@@ -1,3 +1,5 @@
+use crate::services::analyzer_service::{ProjectAnalysis, WorkspaceAnalysis};
+use crate::services::parser_service::TechnologyOrigin;
 use crate::services::AnalyzerService;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
@@ -7,11 +9,46 @@ use std::path::PathBuf;
 pub struct ProjectAnalysisResult {
     pub project_type: String,
     pub detected_technologies: Vec<String>,
+    pub technology_origins: Vec<TechnologyOrigin>,
     pub file_counts: HashMap<String, usize>,
     pub total_files: usize,
     pub suggested_agents: Vec<String>,
 }
 
+impl From<ProjectAnalysis> for ProjectAnalysisResult {
+    fn from(analysis: ProjectAnalysis) -> Self {
+        ProjectAnalysisResult {
+            project_type: format!("{:?}", analysis.project_type),
+            detected_technologies: analysis.detected_technologies,
+            technology_origins: analysis.technology_origins,
+            file_counts: analysis.file_counts,
+            total_files: analysis.total_files,
+            suggested_agents: analysis.suggested_agents,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct WorkspaceAnalysisResult {
+    pub root: ProjectAnalysisResult,
+    pub members: Vec<(String, ProjectAnalysisResult)>,
+    pub suggested_agents: Vec<String>,
+}
+
+impl From<WorkspaceAnalysis> for WorkspaceAnalysisResult {
+    fn from(analysis: WorkspaceAnalysis) -> Self {
+        WorkspaceAnalysisResult {
+            root: analysis.root.into(),
+            members: analysis
+                .members
+                .into_iter()
+                .map(|(path, member)| (path.to_string_lossy().to_string(), member.into()))
+                .collect(),
+            suggested_agents: analysis.suggested_agents,
+        }
+    }
+}
+
 #[tauri::command]
 pub fn analyze_project(path: String) -> Result<ProjectAnalysisResult, String> {
     let analyzer = AnalyzerService::new();
@@ -21,11 +58,17 @@ pub fn analyze_project(path: String) -> Result<ProjectAnalysisResult, String> {
         .analyze_project(&path_buf)
         .map_err(|e| e.to_string())?;
 
-    Ok(ProjectAnalysisResult {
-        project_type: format!("{:?}", analysis.project_type),
-        detected_technologies: analysis.detected_technologies,
-        file_counts: analysis.file_counts,
-        total_files: analysis.total_files,
-        suggested_agents: analysis.suggested_agents,
-    })
+    Ok(analysis.into())
+}
+
+#[tauri::command]
+pub fn analyze_workspace(path: String) -> Result<WorkspaceAnalysisResult, String> {
+    let analyzer = AnalyzerService::new();
+    let path_buf = PathBuf::from(&path);
+
+    let analysis = analyzer
+        .analyze_workspace(&path_buf, 5)
+        .map_err(|e| e.to_string())?;
+
+    Ok(analysis.into())
 }
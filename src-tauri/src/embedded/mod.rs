@@ -19,6 +19,14 @@ pub struct AgentTemplate {
     pub category: String,
     pub template: String,
     pub suggested_for: Vec<String>,
+    /// Whether this template came from the baked-in library or a
+    /// project-local override, so the UI can distinguish and edit them.
+    #[serde(default = "default_agent_source")]
+    pub source: String,
+}
+
+fn default_agent_source() -> String {
+    "embedded".to_string()
 }
 
 pub fn load_embedded_library() -> Result<AgentLibrary> {
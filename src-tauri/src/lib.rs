@@ -2,6 +2,8 @@
 mod commands;
 mod embedded;
 mod models;
+#[cfg(feature = "server")]
+mod server;
 mod services;
 mod utils;
 
@@ -13,6 +15,14 @@ pub use services::*;
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
+    // Headless mode: `--serve <addr>` skips the Tauri GUI entirely and
+    // serves the same capabilities over HTTP instead.
+    #[cfg(feature = "server")]
+    if let Some(addr) = server::parse_serve_flag(std::env::args()) {
+        server::run_blocking(&addr).expect("headless server failed");
+        return;
+    }
+
     tauri::Builder::default()
         .plugin(tauri_plugin_shell::init())
         .plugin(tauri_plugin_dialog::init())
@@ -21,11 +31,19 @@ pub fn run() {
             commands::greet,
             // Git commands
             commands::clone_repo,
+            commands::clone_repo_if_missing,
             commands::is_git_repo,
             commands::get_repo_status,
+            commands::get_repo_status_detailed,
+            commands::list_git_branches,
+            commands::list_git_tags,
+            commands::get_recent_commits,
             commands::create_git_commit,
             // Project commands
             commands::analyze_project,
+            commands::analyze_workspace,
+            // Dependency commands
+            commands::check_outdated_dependencies,
             // Agent commands
             commands::get_agent_library,
             commands::get_agents_by_category,
@@ -34,6 +52,8 @@ pub fn run() {
             commands::generate_agent_file,
             commands::save_agent_file,
             commands::get_suggested_agents,
+            commands::save_agent_template,
+            commands::list_custom_agents,
             // Config commands
             commands::load_teamforge_config,
             commands::save_teamforge_config,
@@ -42,6 +62,9 @@ pub fn run() {
             commands::teamforge_exists,
             commands::initialize_teamforge,
             commands::ensure_claude_agents_dir,
+            commands::save_forge_config,
+            commands::load_forge_config,
+            commands::migrate_config,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -49,3 +49,15 @@ pub struct ProjectInfo {
     pub path: String,
     pub detected_technologies: Vec<String>,
 }
+
+/// Endpoint and auth settings for a configured remote forge (GitHub,
+/// GitLab, Forgejo, ...), persisted so `clone_repo` can resolve a
+/// `forge:owner/repo` shorthand without re-entering credentials each time.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForgeConfig {
+    pub forge: String,
+    pub endpoint: Option<String>,
+    /// Name of the environment variable holding the auth token, read at
+    /// request time so the token itself never lives in this file.
+    pub auth_env_var: Option<String>,
+}
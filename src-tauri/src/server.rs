@@ -0,0 +1,169 @@
+//! Headless HTTP API mode, gated behind the `server` Cargo feature and a
+//! `--serve <addr>` flag. Exposes the same capabilities as the Tauri
+//! commands over a local axum server, so TeamForge can batch-analyze
+//! repositories or be driven by other tooling in CI without a GUI.
+
+use crate::models::TeamForgeConfig;
+use crate::services::parser_service::{DependencyCheckReport, TechnologyOrigin};
+use crate::services::{AnalyzerService, ConfigService, GitService, ParserService};
+use axum::extract::{Query, State};
+use axum::http::StatusCode;
+use axum::routing::{get, post};
+use axum::{Json, Router};
+use serde::{Deserialize, Serialize};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+
+struct ServerState {
+    analyzer: AnalyzerService,
+    config: ConfigService,
+    git: GitService,
+    parser: ParserService,
+}
+
+/// Pull the address following a `--serve` flag out of the process
+/// arguments, if present.
+pub fn parse_serve_flag<I: Iterator<Item = String>>(mut args: I) -> Option<String> {
+    while let Some(arg) = args.next() {
+        if arg == "--serve" {
+            return args.next();
+        }
+    }
+    None
+}
+
+/// Start the headless HTTP server and block the current thread until it
+/// exits, driving it on a runtime of its own since `tauri::Builder::run`
+/// isn't used in this mode.
+pub fn run_blocking(addr: &str) -> anyhow::Result<()> {
+    let addr: SocketAddr = addr.parse()?;
+    let runtime = tokio::runtime::Runtime::new()?;
+    runtime.block_on(serve(addr))
+}
+
+async fn serve(addr: SocketAddr) -> anyhow::Result<()> {
+    let state = Arc::new(ServerState {
+        analyzer: AnalyzerService::new(),
+        config: ConfigService::new(),
+        git: GitService::new(),
+        parser: ParserService::new(),
+    });
+
+    let app = Router::new()
+        .route("/analyze", post(analyze))
+        .route("/config", get(get_config).put(put_config))
+        .route("/git/commit", post(git_commit))
+        .route("/dependencies/outdated", post(check_outdated))
+        .with_state(state);
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    axum::serve(listener, app).await?;
+    Ok(())
+}
+
+fn internal_error(err: impl std::fmt::Display) -> (StatusCode, String) {
+    (StatusCode::INTERNAL_SERVER_ERROR, err.to_string())
+}
+
+#[derive(Deserialize)]
+struct AnalyzeRequest {
+    path: String,
+}
+
+#[derive(Serialize)]
+struct AnalyzeResponse {
+    detected_technologies: Vec<String>,
+    technology_origins: Vec<TechnologyOrigin>,
+}
+
+async fn analyze(
+    State(state): State<Arc<ServerState>>,
+    Json(body): Json<AnalyzeRequest>,
+) -> Result<Json<AnalyzeResponse>, (StatusCode, String)> {
+    state
+        .analyzer
+        .analyze_project(&PathBuf::from(body.path))
+        .map(|analysis| {
+            Json(AnalyzeResponse {
+                detected_technologies: analysis.detected_technologies,
+                technology_origins: analysis.technology_origins,
+            })
+        })
+        .map_err(internal_error)
+}
+
+#[derive(Deserialize)]
+struct ConfigQuery {
+    project_path: String,
+}
+
+async fn get_config(
+    State(state): State<Arc<ServerState>>,
+    Query(query): Query<ConfigQuery>,
+) -> Result<Json<TeamForgeConfig>, (StatusCode, String)> {
+    state
+        .config
+        .load_config(&PathBuf::from(query.project_path))
+        .map(Json)
+        .map_err(internal_error)
+}
+
+#[derive(Deserialize)]
+struct PutConfigRequest {
+    project_path: String,
+    config: TeamForgeConfig,
+}
+
+async fn put_config(
+    State(state): State<Arc<ServerState>>,
+    Json(body): Json<PutConfigRequest>,
+) -> Result<Json<&'static str>, (StatusCode, String)> {
+    state
+        .config
+        .save_config(&body.config, &PathBuf::from(body.project_path))
+        .map(|_| Json("ok"))
+        .map_err(internal_error)
+}
+
+#[derive(Deserialize)]
+struct GitCommitRequest {
+    path: String,
+    message: String,
+    files: Vec<String>,
+}
+
+async fn git_commit(
+    State(state): State<Arc<ServerState>>,
+    Json(body): Json<GitCommitRequest>,
+) -> Result<Json<String>, (StatusCode, String)> {
+    let repo = state
+        .git
+        .open_repository(&PathBuf::from(body.path))
+        .map_err(internal_error)?;
+
+    let file_refs: Vec<&str> = body.files.iter().map(String::as_str).collect();
+
+    state
+        .git
+        .create_commit(&repo, &body.message, &file_refs)
+        .map(|oid| Json(oid.to_string()))
+        .map_err(internal_error)
+}
+
+#[derive(Deserialize)]
+struct OutdatedRequest {
+    manifest_path: String,
+}
+
+async fn check_outdated(
+    State(state): State<Arc<ServerState>>,
+    Json(body): Json<OutdatedRequest>,
+) -> Result<Json<DependencyCheckReport>, (StatusCode, String)> {
+    state
+        .parser
+        .check_outdated(&PathBuf::from(body.manifest_path))
+        .await
+        .map(Json)
+        .map_err(internal_error)
+}
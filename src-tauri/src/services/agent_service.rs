@@ -1,8 +1,13 @@
 use crate::embedded::{load_embedded_library, AgentLibrary, AgentTemplate};
-use anyhow::Result;
+use anyhow::{Context, Result};
 use std::fs;
 use std::path::Path;
 
+/// Directory (or single-file) locations, relative to a project root, that
+/// may hold user-defined agent templates layered over the embedded library.
+const CUSTOM_AGENTS_DIR: &str = ".teamforge/agents";
+const CUSTOM_AGENTS_FILE: &str = ".teamforge/agents.json";
+
 pub struct AgentService;
 
 impl AgentService {
@@ -15,9 +20,99 @@ impl AgentService {
         load_embedded_library()
     }
 
+    /// Load the agent library, optionally layering a project-local library
+    /// (`.teamforge/agents/*.json` or `.teamforge/agents.json`) over the
+    /// embedded one: entries whose id already exists override the embedded
+    /// template, new ids are appended, and categories are unioned.
+    pub fn get_library(&self, project_path: Option<&Path>) -> Result<AgentLibrary> {
+        let mut library = self.get_embedded_library()?;
+
+        if let Some(project_path) = project_path {
+            let custom_agents = self.load_custom_agents(project_path)?;
+            self.merge_custom_agents(&mut library, custom_agents);
+        }
+
+        Ok(library)
+    }
+
+    /// Read every custom agent template defined for a project, without
+    /// merging them over the embedded library.
+    pub fn list_custom_agents(&self, project_path: &Path) -> Result<Vec<AgentTemplate>> {
+        self.load_custom_agents(project_path)
+    }
+
+    /// Save a custom agent template as `.teamforge/agents/{id}.json`
+    pub fn save_agent_template(&self, agent: &AgentTemplate, project_path: &Path) -> Result<()> {
+        let agents_dir = project_path.join(CUSTOM_AGENTS_DIR);
+        fs::create_dir_all(&agents_dir)?;
+
+        let mut agent = agent.clone();
+        agent.source = "custom".to_string();
+
+        let file_path = agents_dir.join(format!("{}.json", agent.id));
+        let content = serde_json::to_string_pretty(&agent)?;
+        fs::write(&file_path, content)
+            .with_context(|| format!("Failed to write custom agent to {:?}", file_path))
+    }
+
+    fn load_custom_agents(&self, project_path: &Path) -> Result<Vec<AgentTemplate>> {
+        let mut agents = Vec::new();
+
+        let agents_dir = project_path.join(CUSTOM_AGENTS_DIR);
+        if agents_dir.is_dir() {
+            for entry in fs::read_dir(&agents_dir)? {
+                let path = entry?.path();
+                if path.extension().and_then(|ext| ext.to_str()) != Some("json") {
+                    continue;
+                }
+
+                let content = fs::read_to_string(&path)
+                    .with_context(|| format!("Failed to read custom agent at {:?}", path))?;
+                let mut agent: AgentTemplate = serde_json::from_str(&content)
+                    .with_context(|| format!("Failed to parse custom agent at {:?}", path))?;
+                agent.source = "custom".to_string();
+                agents.push(agent);
+            }
+        }
+
+        let agents_file = project_path.join(CUSTOM_AGENTS_FILE);
+        if agents_file.is_file() {
+            let content = fs::read_to_string(&agents_file)
+                .with_context(|| format!("Failed to read {:?}", agents_file))?;
+            let mut parsed: Vec<AgentTemplate> = serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse {:?}", agents_file))?;
+            for agent in &mut parsed {
+                agent.source = "custom".to_string();
+            }
+            agents.extend(parsed);
+        }
+
+        Ok(agents)
+    }
+
+    /// Layer `custom_agents` over `library`: an id that already exists
+    /// overrides the embedded template, a new id is appended, and its
+    /// category is unioned into `library.categories`.
+    fn merge_custom_agents(&self, library: &mut AgentLibrary, custom_agents: Vec<AgentTemplate>) {
+        for custom in custom_agents {
+            if !library.categories.contains(&custom.category) {
+                library.categories.push(custom.category.clone());
+            }
+
+            match library.agents.iter_mut().find(|agent| agent.id == custom.id) {
+                Some(existing) => *existing = custom,
+                None => library.agents.push(custom),
+            }
+        }
+    }
+
     /// Get agents by category
-    pub fn get_agents_by_category(&self, category: &str) -> Result<Vec<AgentTemplate>> {
-        let library = self.get_embedded_library()?;
+    pub fn get_agents_by_category(
+        &self,
+        category: &str,
+        project_path: Option<&Path>,
+    ) -> Result<Vec<AgentTemplate>> {
+        let library = self.get_library(project_path)?;
         Ok(library
             .agents
             .into_iter()
@@ -26,25 +121,48 @@ impl AgentService {
     }
 
     /// Get agent by ID
-    pub fn get_agent_by_id(&self, id: &str) -> Result<Option<AgentTemplate>> {
-        let library = self.get_embedded_library()?;
+    pub fn get_agent_by_id(
+        &self,
+        id: &str,
+        project_path: Option<&Path>,
+    ) -> Result<Option<AgentTemplate>> {
+        let library = self.get_library(project_path)?;
         Ok(library.agents.into_iter().find(|agent| agent.id == id))
     }
 
-    /// Search agents by keyword
-    pub fn search_agents(&self, keyword: &str) -> Result<Vec<AgentTemplate>> {
-        let library = self.get_embedded_library()?;
-        let keyword_lower = keyword.to_lowercase();
+    /// Search agents by keyword using fuzzy subsequence matching, ranked by relevance
+    pub fn search_agents(
+        &self,
+        keyword: &str,
+        project_path: Option<&Path>,
+    ) -> Result<Vec<AgentTemplate>> {
+        let library = self.get_library(project_path)?;
+        let query = keyword.to_lowercase();
 
-        Ok(library
+        let mut scored: Vec<(i32, AgentTemplate)> = library
             .agents
             .into_iter()
-            .filter(|agent| {
-                agent.name.to_lowercase().contains(&keyword_lower)
-                    || agent.description.to_lowercase().contains(&keyword_lower)
-                    || agent.tags.iter().any(|tag| tag.to_lowercase().contains(&keyword_lower))
+            .filter_map(|agent| {
+                let name_score = fuzzy_score(&query, &agent.name);
+                let desc_score = fuzzy_score(&query, &agent.description);
+                let tags_score = agent
+                    .tags
+                    .iter()
+                    .map(|tag| fuzzy_score(&query, tag))
+                    .max()
+                    .unwrap_or(0);
+
+                let score = name_score.max(desc_score).max(tags_score);
+                if score > 0 {
+                    Some((score, agent))
+                } else {
+                    None
+                }
             })
-            .collect())
+            .collect();
+
+        scored.sort_by(|a, b| b.0.cmp(&a.0));
+        Ok(scored.into_iter().map(|(_, agent)| agent).collect())
     }
 
     /// Generate Claude Code agent markdown file
@@ -85,12 +203,32 @@ impl AgentService {
         Ok(())
     }
 
+    /// Suggest known agent ids close to `id`, for "did you mean" hints when
+    /// `get_agent_by_id` comes up empty.
+    pub fn suggest_similar_ids(&self, id: &str, project_path: Option<&Path>) -> Result<Vec<String>> {
+        let library = self.get_library(project_path)?;
+        let ids: Vec<String> = library.agents.into_iter().map(|agent| agent.id).collect();
+        Ok(closest_matches(id, &ids))
+    }
+
+    /// Suggest known categories close to `category`, for "did you mean" hints
+    /// when `get_agents_by_category` comes up empty.
+    pub fn suggest_similar_categories(
+        &self,
+        category: &str,
+        project_path: Option<&Path>,
+    ) -> Result<Vec<String>> {
+        let library = self.get_library(project_path)?;
+        Ok(closest_matches(category, &library.categories))
+    }
+
     /// Get suggested agents for technologies
     pub fn get_suggested_agents_for_technologies(
         &self,
         technologies: &[String],
+        project_path: Option<&Path>,
     ) -> Result<Vec<AgentTemplate>> {
-        let library = self.get_embedded_library()?;
+        let library = self.get_library(project_path)?;
 
         Ok(library
             .agents
@@ -112,3 +250,193 @@ impl Default for AgentService {
         Self::new()
     }
 }
+
+/// Score how well `query` matches `candidate` as a subsequence, rewarding
+/// consecutive matches and word-boundary matches. Returns 0 if `query` is
+/// not a subsequence of `candidate` (lowercased).
+fn fuzzy_score(query: &str, candidate: &str) -> i32 {
+    if query.is_empty() {
+        return 0;
+    }
+
+    let original_chars: Vec<char> = candidate.chars().collect();
+
+    // `char::to_lowercase()` can expand a single char into several (e.g.
+    // 'İ' -> "i̇"), so lowercasing the whole string and collecting its chars
+    // would desync from `original_chars` by index. Lowercase char-by-char
+    // instead and track which original index each lowered char came from,
+    // so `source_idx[match_idx]` always stays a valid index into
+    // `original_chars`.
+    let mut candidate_chars: Vec<char> = Vec::new();
+    let mut source_idx: Vec<usize> = Vec::new();
+    for (i, c) in original_chars.iter().enumerate() {
+        for lc in c.to_lowercase() {
+            candidate_chars.push(lc);
+            source_idx.push(i);
+        }
+    }
+
+    let query_chars: Vec<char> = query.chars().collect();
+
+    let mut score = 0i32;
+    let mut candidate_idx = 0;
+    let mut last_match_idx: Option<usize> = None;
+
+    for &q in &query_chars {
+        let mut found = None;
+        while candidate_idx < candidate_chars.len() {
+            if candidate_chars[candidate_idx] == q {
+                found = Some(candidate_idx);
+                break;
+            }
+            candidate_idx += 1;
+        }
+
+        let match_idx = match found {
+            Some(idx) => idx,
+            None => return 0,
+        };
+
+        let orig_idx = source_idx[match_idx];
+        let is_word_boundary = match_idx == 0
+            || matches!(candidate_chars[match_idx - 1], '-' | '_' | ' ')
+            || (orig_idx > 0
+                && original_chars[orig_idx].is_uppercase()
+                && !original_chars[orig_idx - 1].is_uppercase());
+
+        if is_word_boundary {
+            score += 30;
+        }
+
+        match last_match_idx {
+            Some(prev) if prev + 1 == match_idx => score += 15,
+            Some(prev) => score -= (match_idx - prev - 1) as i32,
+            None => score -= match_idx as i32,
+        }
+
+        last_match_idx = Some(match_idx);
+        candidate_idx = match_idx + 1;
+    }
+
+    score.max(1)
+}
+
+/// Levenshtein edit distance between `a` and `b`, computed with the
+/// standard single-row dynamic-programming recurrence.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b_chars: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b_chars.len()).collect();
+
+    for (i, a_char) in a.chars().enumerate() {
+        let mut prev_diag = row[0];
+        row[0] = i + 1;
+
+        for (j, &b_char) in b_chars.iter().enumerate() {
+            let tmp = row[j + 1];
+            row[j + 1] = std::cmp::min(
+                std::cmp::min(row[j + 1] + 1, row[j] + 1),
+                prev_diag + (a_char != b_char) as usize,
+            );
+            prev_diag = tmp;
+        }
+    }
+
+    row[b_chars.len()]
+}
+
+/// Return the candidates within `max(2, len/3)` edit distance of `query`,
+/// sorted by ascending distance.
+fn closest_matches(query: &str, candidates: &[String]) -> Vec<String> {
+    let threshold = std::cmp::max(2, query.len() / 3);
+
+    let mut matches: Vec<(usize, String)> = candidates
+        .iter()
+        .map(|candidate| (edit_distance(query, candidate), candidate.clone()))
+        .filter(|(distance, _)| *distance <= threshold)
+        .collect();
+
+    matches.sort_by_key(|(distance, _)| *distance);
+    matches.into_iter().map(|(_, candidate)| candidate).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fuzzy_score_rejects_non_subsequence() {
+        assert_eq!(fuzzy_score("xyz", "code-reviewer"), 0);
+    }
+
+    #[test]
+    fn fuzzy_score_rewards_word_boundary_matches() {
+        let boundary = fuzzy_score("cr", "code-reviewer");
+        let mid_word = fuzzy_score("de", "code-reviewer");
+        assert!(boundary > mid_word);
+    }
+
+    #[test]
+    fn fuzzy_score_rewards_consecutive_matches_over_scattered() {
+        let consecutive = fuzzy_score("cod", "code-reviewer");
+        let scattered = fuzzy_score("cer", "code-reviewer");
+        assert!(consecutive > scattered);
+    }
+
+    #[test]
+    fn fuzzy_score_handles_multi_char_lowercasing_without_desync() {
+        // 'İ'.to_lowercase() expands to two chars ("i\u{307}"), which is
+        // exactly the desync case `source_idx` exists to guard against: if
+        // `orig_idx` ever pointed past the end of `original_chars` this
+        // would panic instead of scoring the match.
+        assert!(fuzzy_score("ist", "İstanbul") > 0);
+    }
+
+    #[test]
+    fn fuzzy_score_empty_query_is_zero() {
+        assert_eq!(fuzzy_score("", "anything"), 0);
+    }
+
+    #[test]
+    fn edit_distance_identical_strings_is_zero() {
+        assert_eq!(edit_distance("reviewer", "reviewer"), 0);
+    }
+
+    #[test]
+    fn edit_distance_counts_single_substitution() {
+        assert_eq!(edit_distance("reviewer", "revieweR"), 1);
+    }
+
+    #[test]
+    fn edit_distance_counts_insertion_and_deletion() {
+        assert_eq!(edit_distance("agent", "agents"), 1);
+        assert_eq!(edit_distance("agents", "agent"), 1);
+    }
+
+    #[test]
+    fn edit_distance_empty_string_is_length_of_other() {
+        assert_eq!(edit_distance("", "agent"), 5);
+        assert_eq!(edit_distance("agent", ""), 5);
+    }
+
+    #[test]
+    fn closest_matches_filters_by_threshold_and_sorts_ascending() {
+        let candidates = vec![
+            "code-reviewer".to_string(),
+            "cod-reviewer".to_string(),
+            "unrelated-agent".to_string(),
+        ];
+
+        let matches = closest_matches("code-reviewer", &candidates);
+
+        assert_eq!(
+            matches,
+            vec!["code-reviewer".to_string(), "cod-reviewer".to_string()]
+        );
+    }
+
+    #[test]
+    fn closest_matches_empty_when_nothing_within_threshold() {
+        let candidates = vec!["completely-different-name".to_string()];
+        assert!(closest_matches("agent", &candidates).is_empty());
+    }
+}
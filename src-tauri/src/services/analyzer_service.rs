@@ -1,11 +1,18 @@
 use crate::models::{ProjectType};
 use anyhow::Result;
 use std::collections::HashMap;
+use std::collections::HashSet;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use walkdir::WalkDir;
 
-use super::parser_service::ParserService;
+use super::parser_service::{ParserService, TechnologyOrigin};
+
+/// Directories that never contain a sub-project worth analyzing on their own.
+const SKIPPED_DIRS: [&str; 4] = ["node_modules", "target", ".git", "dist"];
+
+/// Manifest files that mark a directory as a distinct sub-project.
+const MANIFEST_FILES: [&str; 4] = ["package.json", "Cargo.toml", "go.mod", "requirements.txt"];
 
 pub struct AnalyzerService {
     parser: ParserService,
@@ -21,25 +28,40 @@ impl AnalyzerService {
     /// Analyze a project and detect its type and technologies
     pub fn analyze_project(&self, path: &Path) -> Result<ProjectAnalysis> {
         let mut technologies = Vec::new();
+        let mut technology_origins = Vec::new();
         let mut file_counts: HashMap<String, usize> = HashMap::new();
         let mut total_files = 0;
+        let mut cargo_workspace_members = 0;
 
         // Check for common manifest files
         if path.join("package.json").exists() {
             if let Ok(techs) = self.parser.parse_package_json(&path.join("package.json")) {
                 technologies.extend(techs);
             }
+            if let Ok(origins) = self.parser.analyze_dependency_graph(&path.join("package.json")) {
+                technology_origins.extend(origins);
+            }
         }
 
         if path.join("requirements.txt").exists() {
             if let Ok(techs) = self.parser.parse_requirements_txt(&path.join("requirements.txt")) {
                 technologies.extend(techs);
             }
+            if let Ok(origins) = self
+                .parser
+                .analyze_dependency_graph(&path.join("requirements.txt"))
+            {
+                technology_origins.extend(origins);
+            }
         }
 
         if path.join("Cargo.toml").exists() {
-            if let Ok(techs) = self.parser.parse_cargo_toml(&path.join("Cargo.toml")) {
-                technologies.extend(techs);
+            if let Ok(info) = self.parser.parse_cargo_workspace(&path.join("Cargo.toml")) {
+                technologies.extend(info.technologies);
+                cargo_workspace_members = info.workspace_members.len();
+            }
+            if let Ok(origins) = self.parser.analyze_dependency_graph(&path.join("Cargo.toml")) {
+                technology_origins.extend(origins);
             }
         }
 
@@ -47,6 +69,17 @@ impl AnalyzerService {
             if let Ok(techs) = self.parser.parse_go_mod(&path.join("go.mod")) {
                 technologies.extend(techs);
             }
+            if let Ok(origins) = self.parser.analyze_dependency_graph(&path.join("go.mod")) {
+                technology_origins.extend(origins);
+            }
+        }
+
+        // A transitive technology found only through the dependency graph
+        // (e.g. a framework pulled in by a direct dependency rather than
+        // declared directly) still belongs in `detected_technologies`, the
+        // field the rest of the analyzer keys off of.
+        for origin in &technology_origins {
+            technologies.push(origin.technology.clone());
         }
 
         // Count file types (limited depth to avoid performance issues)
@@ -64,7 +97,8 @@ impl AnalyzerService {
         }
 
         // Detect project type
-        let project_type = self.detect_project_type(&technologies, &file_counts);
+        let project_type =
+            self.detect_project_type(&technologies, &file_counts, cargo_workspace_members);
 
         // Suggest agents based on project type and technologies
         let suggested_agents = self.suggest_agents(&project_type, &technologies);
@@ -76,6 +110,7 @@ impl AnalyzerService {
         Ok(ProjectAnalysis {
             project_type,
             detected_technologies: technologies,
+            technology_origins,
             file_counts,
             total_files,
             suggested_agents,
@@ -87,6 +122,7 @@ impl AnalyzerService {
         &self,
         technologies: &[String],
         file_counts: &HashMap<String, usize>,
+        cargo_workspace_members: usize,
     ) -> ProjectType {
         let has_frontend = technologies.iter().any(|t| {
             matches!(
@@ -110,9 +146,20 @@ impl AnalyzerService {
         let has_desktop = technologies.contains(&"tauri".to_string())
             || technologies.contains(&"electron".to_string());
 
+        // A Rust workspace split across several member crates (e.g. an API
+        // crate plus a shared-types crate) is usually a fullstack product
+        // even though only one backend framework shows up in the root deps.
+        let is_large_workspace = cargo_workspace_members >= 3;
+
         match (has_frontend, has_backend, has_mobile, has_desktop) {
             (true, true, _, _) => ProjectType::WebFullstack,
-            (_, true, false, false) => ProjectType::BackendApi,
+            (_, true, false, false) => {
+                if is_large_workspace {
+                    ProjectType::WebFullstack
+                } else {
+                    ProjectType::BackendApi
+                }
+            }
             (true, false, false, false) => ProjectType::Frontend,
             (_, _, true, _) => ProjectType::Mobile,
             (_, _, _, true) => ProjectType::Desktop,
@@ -183,6 +230,84 @@ impl AnalyzerService {
 
         agents.iter().map(|s| s.to_string()).collect()
     }
+
+    /// Analyze a potential monorepo: the root project plus every discovered
+    /// sub-project, with suggested agents unioned across all of them.
+    pub fn analyze_workspace(&self, path: &Path, max_depth: usize) -> Result<WorkspaceAnalysis> {
+        let root = self.analyze_project(path)?;
+
+        let mut member_paths = self.discover_sub_projects(path, max_depth);
+
+        if path.join("Cargo.toml").exists() {
+            if let Ok(info) = self.parser.parse_cargo_workspace(&path.join("Cargo.toml")) {
+                for member in info.workspace_members {
+                    if member != path && !member_paths.contains(&member) {
+                        member_paths.push(member);
+                    }
+                }
+            }
+        }
+
+        let mut members = Vec::new();
+        for member_path in member_paths {
+            let analysis = self.analyze_project(&member_path)?;
+            members.push((member_path, analysis));
+        }
+
+        let mut suggested_agents: Vec<String> = root.suggested_agents.clone();
+        for (_, member) in &members {
+            suggested_agents.extend(member.suggested_agents.iter().cloned());
+        }
+        suggested_agents.sort();
+        suggested_agents.dedup();
+
+        Ok(WorkspaceAnalysis {
+            root,
+            members,
+            suggested_agents,
+        })
+    }
+
+    /// Walk the tree below `path` (excluding `path` itself) looking for
+    /// directories containing a recognized manifest, skipping directories
+    /// that are never worth treating as a distinct sub-project.
+    fn discover_sub_projects(&self, path: &Path, max_depth: usize) -> Vec<PathBuf> {
+        let mut sub_projects = Vec::new();
+        let mut seen: HashSet<PathBuf> = HashSet::new();
+
+        for entry in WalkDir::new(path)
+            .max_depth(max_depth)
+            .into_iter()
+            .filter_entry(|e| {
+                e.file_type().is_file()
+                    || !e
+                        .file_name()
+                        .to_str()
+                        .map(|name| SKIPPED_DIRS.contains(&name))
+                        .unwrap_or(false)
+            })
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_type().is_file())
+        {
+            let Some(dir) = entry.path().parent() else {
+                continue;
+            };
+
+            if dir == path {
+                continue;
+            }
+
+            let Some(file_name) = entry.file_name().to_str() else {
+                continue;
+            };
+
+            if MANIFEST_FILES.contains(&file_name) && seen.insert(dir.to_path_buf()) {
+                sub_projects.push(dir.to_path_buf());
+            }
+        }
+
+        sub_projects
+    }
 }
 
 impl Default for AnalyzerService {
@@ -195,7 +320,21 @@ impl Default for AnalyzerService {
 pub struct ProjectAnalysis {
     pub project_type: ProjectType,
     pub detected_technologies: Vec<String>,
+    /// Which direct dependency introduced each technology in
+    /// `detected_technologies` that was resolved via the dependency graph
+    /// (see `ParserService::analyze_dependency_graph`), directly or
+    /// transitively.
+    pub technology_origins: Vec<TechnologyOrigin>,
     pub file_counts: HashMap<String, usize>,
     pub total_files: usize,
     pub suggested_agents: Vec<String>,
 }
+
+/// Analysis of a monorepo: the root project plus every discovered
+/// sub-project, each analyzed independently.
+#[derive(Debug, Clone)]
+pub struct WorkspaceAnalysis {
+    pub root: ProjectAnalysis,
+    pub members: Vec<(PathBuf, ProjectAnalysis)>,
+    pub suggested_agents: Vec<String>,
+}
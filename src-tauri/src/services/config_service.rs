@@ -1,7 +1,21 @@
-use crate::models::TeamForgeConfig;
+use crate::models::{ForgeConfig, TeamForgeConfig};
 use anyhow::{Context, Result};
+use serde_json::Value;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+
+/// Schema version this binary understands. `load_config` migrates any file
+/// saved with an older version up to this one before deserializing;
+/// `validate_config` flags a file saved with a *newer* version, since this
+/// build would silently drop fields it doesn't know about.
+const CURRENT_CONFIG_VERSION: &str = "1.0.0";
+
+/// Ordered chain of schema migrations, each one transforming the raw
+/// untyped config `Value` from its `from` version to its `to` version.
+/// `load_config` walks this chain starting from whatever version a file
+/// was saved with, so new fields can be introduced without breaking older
+/// `.teamforge/config.json` files already on disk.
+const MIGRATIONS: &[(&str, &str, fn(&mut Value))] = &[];
 
 pub struct ConfigService;
 
@@ -10,15 +24,33 @@ impl ConfigService {
         ConfigService
     }
 
-    /// Load TeamForge config from .teamforge/config.json
+    /// Load TeamForge config from .teamforge/config.json, migrating it to
+    /// `CURRENT_CONFIG_VERSION` first and persisting the upgraded file if
+    /// it was saved with an older schema version.
     pub fn load_config(&self, project_path: &Path) -> Result<TeamForgeConfig> {
         let config_path = project_path.join(".teamforge").join("config.json");
 
         let content = fs::read_to_string(&config_path)
             .with_context(|| format!("Failed to read config at {:?}", config_path))?;
 
-        serde_json::from_str(&content)
-            .with_context(|| "Failed to parse TeamForge config")
+        let mut raw: Value =
+            serde_json::from_str(&content).with_context(|| "Failed to parse TeamForge config")?;
+
+        if migrate_config_value(&mut raw)? {
+            let migrated: TeamForgeConfig = serde_json::from_value(raw)
+                .with_context(|| "Failed to parse migrated TeamForge config")?;
+            self.save_config(&migrated, project_path)?;
+            return Ok(migrated);
+        }
+
+        serde_json::from_value(raw).with_context(|| "Failed to parse TeamForge config")
+    }
+
+    /// Force a migration check against the saved config, even if the caller
+    /// isn't otherwise touching the config. The file is rewritten only when
+    /// a migration actually ran.
+    pub fn migrate_config(&self, project_path: &Path) -> Result<TeamForgeConfig> {
+        self.load_config(project_path)
     }
 
     /// Save TeamForge config to .teamforge/config.json
@@ -85,6 +117,13 @@ impl ConfigService {
             warnings.push("No active agents configured".to_string());
         }
 
+        if version_is_newer(&config.version, CURRENT_CONFIG_VERSION) {
+            warnings.push(format!(
+                "Config version {} is newer than this build understands ({}); saving may drop unrecognized fields",
+                config.version, CURRENT_CONFIG_VERSION
+            ));
+        }
+
         Ok(warnings)
     }
 
@@ -116,6 +155,42 @@ impl ConfigService {
         fs::create_dir_all(&claude_agents_dir)?;
         Ok(())
     }
+
+    /// Load the configured endpoint/auth settings for `forge` (e.g.
+    /// "github"), if one has been saved. Forge config is a per-user/app
+    /// setting rather than a per-project one (see `global_config_dir`), so
+    /// it's resolvable even before a project directory exists -- notably,
+    /// before `clone_repo` has cloned anything into its target path.
+    pub fn load_forge_config(&self, forge: &str) -> Result<Option<ForgeConfig>> {
+        let forges = self.load_forge_configs()?;
+        Ok(forges.into_iter().find(|config| config.forge == forge))
+    }
+
+    /// Persist (or replace) the endpoint/auth settings for a forge.
+    pub fn save_forge_config(&self, forge_config: &ForgeConfig) -> Result<()> {
+        let mut forges = self.load_forge_configs()?;
+        forges.retain(|config| config.forge != forge_config.forge);
+        forges.push(forge_config.clone());
+
+        let config_dir = global_config_dir();
+        fs::create_dir_all(&config_dir)?;
+
+        let forges_path = config_dir.join("forges.json");
+        let content = serde_json::to_string_pretty(&forges)?;
+        fs::write(&forges_path, content)
+            .with_context(|| format!("Failed to write forge config to {:?}", forges_path))
+    }
+
+    fn load_forge_configs(&self) -> Result<Vec<ForgeConfig>> {
+        let forges_path = global_config_dir().join("forges.json");
+        if !forges_path.exists() {
+            return Ok(Vec::new());
+        }
+
+        let content = fs::read_to_string(&forges_path)
+            .with_context(|| format!("Failed to read forge config at {:?}", forges_path))?;
+        serde_json::from_str(&content).with_context(|| "Failed to parse forge config")
+    }
 }
 
 impl Default for ConfigService {
@@ -123,3 +198,110 @@ impl Default for ConfigService {
         Self::new()
     }
 }
+
+/// Directory for settings that apply across every project rather than a
+/// single one (currently just forge endpoint/auth config). Resolved from
+/// the user's home directory -- `TEAMFORGE_HOME` first so tests/tooling can
+/// redirect it, then the platform's usual home-directory variable -- since
+/// this has to be readable before any particular project directory exists.
+fn global_config_dir() -> PathBuf {
+    let home = std::env::var("TEAMFORGE_HOME")
+        .or_else(|_| std::env::var("HOME"))
+        .or_else(|_| std::env::var("USERPROFILE"))
+        .unwrap_or_else(|_| ".".to_string());
+    Path::new(&home).join(".teamforge")
+}
+
+/// Apply every migration between `raw`'s `version` field and
+/// `CURRENT_CONFIG_VERSION` in order, mutating `raw` in place and updating
+/// its `version` field to match. Returns whether any migration ran, so the
+/// caller knows whether the upgraded config needs to be persisted. Errors
+/// if `raw` is already newer than this binary understands, rather than
+/// silently resaving it with fields it doesn't know about dropped.
+fn migrate_config_value(raw: &mut Value) -> Result<bool> {
+    let mut version = raw
+        .get("version")
+        .and_then(|v| v.as_str())
+        .unwrap_or("1.0.0")
+        .to_string();
+
+    if version_is_newer(&version, CURRENT_CONFIG_VERSION) {
+        anyhow::bail!(
+            "Config version {} is newer than this build understands ({})",
+            version,
+            CURRENT_CONFIG_VERSION
+        );
+    }
+
+    let mut migrated = false;
+    while let Some((_, to, migrate)) = MIGRATIONS.iter().find(|(from, _, _)| *from == version) {
+        migrate(raw);
+        version = to.to_string();
+        migrated = true;
+    }
+
+    if migrated {
+        if let Some(object) = raw.as_object_mut() {
+            object.insert("version".to_string(), Value::String(version));
+        }
+    }
+
+    Ok(migrated)
+}
+
+/// Compares two `major.minor.patch` version strings, treating any
+/// unparseable or missing component as `0`.
+fn version_is_newer(version: &str, current: &str) -> bool {
+    parse_version_tuple(version) > parse_version_tuple(current)
+}
+
+fn parse_version_tuple(version: &str) -> (u64, u64, u64) {
+    let mut parts = version.splitn(3, '.').map(|part| part.parse::<u64>().unwrap_or(0));
+    (
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+        parts.next().unwrap_or(0),
+    )
+}
+
+#[cfg(test)]
+mod migration_tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn current_version_is_not_migrated() {
+        let mut raw = json!({"version": "1.0.0"});
+        let migrated = migrate_config_value(&mut raw).unwrap();
+
+        assert!(!migrated);
+        assert_eq!(raw["version"], "1.0.0");
+    }
+
+    #[test]
+    fn missing_version_defaults_to_current_and_is_not_migrated() {
+        let mut raw = json!({"project": {}});
+        let migrated = migrate_config_value(&mut raw).unwrap();
+
+        assert!(!migrated);
+    }
+
+    #[test]
+    fn newer_version_than_this_build_understands_errors() {
+        let mut raw = json!({"version": "99.0.0"});
+        assert!(migrate_config_value(&mut raw).is_err());
+    }
+
+    #[test]
+    fn version_is_newer_compares_numerically_not_lexically() {
+        // Lexical comparison would get "10.0.0" < "9.0.0" wrong.
+        assert!(version_is_newer("10.0.0", "9.0.0"));
+        assert!(!version_is_newer("9.0.0", "10.0.0"));
+    }
+
+    #[test]
+    fn version_is_newer_treats_missing_components_as_zero() {
+        assert!(!version_is_newer("1.0", "1.0.0"));
+        assert!(version_is_newer("1.1", "1.0.5"));
+    }
+}
@@ -0,0 +1,79 @@
+use super::{bytes_to_percentages, ForgeCredentials, ForgeProvider};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+pub struct ForgejoProvider {
+    endpoint: String,
+    token: Option<String>,
+}
+
+impl ForgejoProvider {
+    pub fn new(credentials: ForgeCredentials) -> Self {
+        ForgejoProvider {
+            // Forgejo is self-hosted, so unlike GitHub/GitLab there's no
+            // sensible default endpoint: the caller must configure one.
+            endpoint: credentials.endpoint.unwrap_or_default(),
+            token: credentials
+                .token
+                .or_else(|| std::env::var("FORGEJO_TOKEN").ok()),
+        }
+    }
+
+    fn request(&self, client: &reqwest::Client, url: &str) -> reqwest::RequestBuilder {
+        let request = client.get(url);
+        match &self.token {
+            Some(token) => request.header("Authorization", format!("token {}", token)),
+            None => request,
+        }
+    }
+}
+
+#[async_trait]
+impl ForgeProvider for ForgejoProvider {
+    fn name(&self) -> &'static str {
+        "forgejo"
+    }
+
+    fn resolve_clone_url(&self, shorthand: &str) -> Result<String> {
+        if self.endpoint.is_empty() {
+            anyhow::bail!("Forgejo provider requires a configured endpoint");
+        }
+        Ok(format!("{}/{}.git", self.endpoint, shorthand))
+    }
+
+    async fn default_branch(&self, shorthand: &str) -> Result<String> {
+        let client = reqwest::Client::new();
+        let url = format!("{}/api/v1/repos/{}", self.endpoint, shorthand);
+
+        let body: serde_json::Value = self
+            .request(&client, &url)
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach Forgejo API at {}", url))?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        body.get("default_branch")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .context("Forgejo API response missing default_branch")
+    }
+
+    async fn language_breakdown(&self, shorthand: &str) -> Result<Vec<(String, f64)>> {
+        let client = reqwest::Client::new();
+        let url = format!("{}/api/v1/repos/{}/languages", self.endpoint, shorthand);
+
+        let bytes_by_language: HashMap<String, u64> = self
+            .request(&client, &url)
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach Forgejo API at {}", url))?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(bytes_to_percentages(bytes_by_language))
+    }
+}
@@ -0,0 +1,78 @@
+use super::{bytes_to_percentages, ForgeCredentials, ForgeProvider};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+const DEFAULT_ENDPOINT: &str = "https://api.github.com";
+
+pub struct GitHubProvider {
+    endpoint: String,
+    token: Option<String>,
+}
+
+impl GitHubProvider {
+    pub fn new(credentials: ForgeCredentials) -> Self {
+        GitHubProvider {
+            endpoint: credentials
+                .endpoint
+                .unwrap_or_else(|| DEFAULT_ENDPOINT.to_string()),
+            token: credentials
+                .token
+                .or_else(|| std::env::var("GITHUB_TOKEN").ok()),
+        }
+    }
+
+    fn request(&self, client: &reqwest::Client, url: &str) -> reqwest::RequestBuilder {
+        let request = client.get(url).header("User-Agent", "teamforge");
+        match &self.token {
+            Some(token) => request.bearer_auth(token),
+            None => request,
+        }
+    }
+}
+
+#[async_trait]
+impl ForgeProvider for GitHubProvider {
+    fn name(&self) -> &'static str {
+        "github"
+    }
+
+    fn resolve_clone_url(&self, shorthand: &str) -> Result<String> {
+        Ok(format!("https://github.com/{}.git", shorthand))
+    }
+
+    async fn default_branch(&self, shorthand: &str) -> Result<String> {
+        let client = reqwest::Client::new();
+        let url = format!("{}/repos/{}", self.endpoint, shorthand);
+
+        let body: serde_json::Value = self
+            .request(&client, &url)
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach GitHub API at {}", url))?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        body.get("default_branch")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .context("GitHub API response missing default_branch")
+    }
+
+    async fn language_breakdown(&self, shorthand: &str) -> Result<Vec<(String, f64)>> {
+        let client = reqwest::Client::new();
+        let url = format!("{}/repos/{}/languages", self.endpoint, shorthand);
+
+        let bytes_by_language: HashMap<String, u64> = self
+            .request(&client, &url)
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach GitHub API at {}", url))?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        Ok(bytes_to_percentages(bytes_by_language))
+    }
+}
@@ -0,0 +1,97 @@
+use super::{ForgeCredentials, ForgeProvider};
+use anyhow::{Context, Result};
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+const DEFAULT_ENDPOINT: &str = "https://gitlab.com/api/v4";
+
+pub struct GitLabProvider {
+    endpoint: String,
+    token: Option<String>,
+}
+
+impl GitLabProvider {
+    pub fn new(credentials: ForgeCredentials) -> Self {
+        GitLabProvider {
+            endpoint: credentials
+                .endpoint
+                .unwrap_or_else(|| DEFAULT_ENDPOINT.to_string()),
+            token: credentials
+                .token
+                .or_else(|| std::env::var("GITLAB_TOKEN").ok()),
+        }
+    }
+
+    fn request(&self, client: &reqwest::Client, url: &str) -> reqwest::RequestBuilder {
+        let request = client.get(url);
+        match &self.token {
+            Some(token) => request.header("PRIVATE-TOKEN", token),
+            None => request,
+        }
+    }
+
+    /// GitLab's project endpoints key on a URL-encoded `owner/repo` path.
+    fn project_id(shorthand: &str) -> String {
+        urlencoding_encode(shorthand)
+    }
+}
+
+#[async_trait]
+impl ForgeProvider for GitLabProvider {
+    fn name(&self) -> &'static str {
+        "gitlab"
+    }
+
+    fn resolve_clone_url(&self, shorthand: &str) -> Result<String> {
+        Ok(format!("https://gitlab.com/{}.git", shorthand))
+    }
+
+    async fn default_branch(&self, shorthand: &str) -> Result<String> {
+        let client = reqwest::Client::new();
+        let url = format!("{}/projects/{}", self.endpoint, Self::project_id(shorthand));
+
+        let body: serde_json::Value = self
+            .request(&client, &url)
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach GitLab API at {}", url))?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        body.get("default_branch")
+            .and_then(|v| v.as_str())
+            .map(|s| s.to_string())
+            .context("GitLab API response missing default_branch")
+    }
+
+    async fn language_breakdown(&self, shorthand: &str) -> Result<Vec<(String, f64)>> {
+        let client = reqwest::Client::new();
+        let url = format!(
+            "{}/projects/{}/languages",
+            self.endpoint,
+            Self::project_id(shorthand)
+        );
+
+        // GitLab already reports languages as percentages, unlike GitHub/Forgejo.
+        let percentages: HashMap<String, f64> = self
+            .request(&client, &url)
+            .send()
+            .await
+            .with_context(|| format!("Failed to reach GitLab API at {}", url))?
+            .error_for_status()?
+            .json()
+            .await?;
+
+        let mut breakdown: Vec<(String, f64)> = percentages.into_iter().collect();
+        breakdown.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        Ok(breakdown)
+    }
+}
+
+/// Minimal percent-encoding for the path segments GitLab's API needs
+/// (`owner/repo` -> `owner%2Frepo`); avoids pulling in a URL-encoding crate
+/// for a single reserved character.
+fn urlencoding_encode(value: &str) -> String {
+    value.replace('/', "%2F")
+}
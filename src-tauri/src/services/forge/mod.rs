@@ -0,0 +1,96 @@
+// Forge abstraction - lets clone_repo work against any Cargo-feature-gated
+// forge (GitHub, GitLab, Forgejo) instead of only plain git URLs.
+
+#[cfg(feature = "github")]
+mod github;
+#[cfg(feature = "gitlab")]
+mod gitlab;
+#[cfg(feature = "forgejo")]
+mod forgejo;
+
+#[cfg(feature = "github")]
+pub use github::GitHubProvider;
+#[cfg(feature = "gitlab")]
+pub use gitlab::GitLabProvider;
+#[cfg(feature = "forgejo")]
+pub use forgejo::ForgejoProvider;
+
+use anyhow::Result;
+use async_trait::async_trait;
+
+/// A remote forge (GitHub, GitLab, Forgejo, ...) that can resolve a
+/// `owner/repo` shorthand into a clone URL and report metadata about the
+/// repository before it's cloned.
+#[async_trait]
+pub trait ForgeProvider: Send + Sync {
+    /// Short identifier for this forge, e.g. "github".
+    fn name(&self) -> &'static str;
+
+    /// Resolve an `owner/repo` shorthand into a full clone URL.
+    fn resolve_clone_url(&self, shorthand: &str) -> Result<String>;
+
+    /// The forge-reported default branch for `shorthand`.
+    async fn default_branch(&self, shorthand: &str) -> Result<String>;
+
+    /// Forge-reported language breakdown as (language, percentage) pairs,
+    /// sorted by descending percentage.
+    async fn language_breakdown(&self, shorthand: &str) -> Result<Vec<(String, f64)>>;
+}
+
+/// Credentials and endpoint needed to talk to a configured forge.
+#[derive(Debug, Clone)]
+pub struct ForgeCredentials {
+    pub endpoint: Option<String>,
+    pub token: Option<String>,
+}
+
+/// Build the provider for a forge name (as used in `clone_repo`'s
+/// `forge:owner/repo` shorthand), e.g. `"github"`. Returns an error if the
+/// forge is unknown or its Cargo feature isn't compiled in.
+pub fn provider_for(forge: &str, credentials: ForgeCredentials) -> Result<Box<dyn ForgeProvider>> {
+    match forge {
+        #[cfg(feature = "github")]
+        "github" => Ok(Box::new(GitHubProvider::new(credentials))),
+        #[cfg(feature = "gitlab")]
+        "gitlab" => Ok(Box::new(GitLabProvider::new(credentials))),
+        #[cfg(feature = "forgejo")]
+        "forgejo" => Ok(Box::new(ForgejoProvider::new(credentials))),
+        other => anyhow::bail!(
+            "Unknown or unsupported forge '{}' (is its Cargo feature enabled?)",
+            other
+        ),
+    }
+}
+
+/// Split a `forge:owner/repo` shorthand (e.g. `github:owner/repo`) into its
+/// forge name and `owner/repo` parts. Returns `None` for a plain URL.
+pub fn parse_shorthand(url: &str) -> Option<(&str, &str)> {
+    let (forge, shorthand) = url.split_once(':')?;
+    // A forge name is a bare identifier; this also rules out scp-style
+    // `git@host:owner/repo.git` URLs.
+    let is_forge_name = !forge.is_empty() && forge.chars().all(|c| c.is_ascii_alphanumeric());
+    if url.contains("://") || !is_forge_name || shorthand.is_empty() {
+        return None;
+    }
+    Some((forge, shorthand))
+}
+
+/// Convert a byte-count-per-language map (as returned by GitHub and Forgejo)
+/// into percentages, sorted by descending share. Lives here rather than on
+/// either forge module so forges that need it stay independently
+/// feature-gated from each other.
+#[cfg(any(feature = "github", feature = "forgejo"))]
+fn bytes_to_percentages(bytes_by_language: std::collections::HashMap<String, u64>) -> Vec<(String, f64)> {
+    let total: u64 = bytes_by_language.values().sum();
+    if total == 0 {
+        return Vec::new();
+    }
+
+    let mut breakdown: Vec<(String, f64)> = bytes_by_language
+        .into_iter()
+        .map(|(language, bytes)| (language, bytes as f64 / total as f64 * 100.0))
+        .collect();
+
+    breakdown.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+    breakdown
+}
@@ -1,5 +1,6 @@
 use anyhow::{Context, Result};
-use git2::Repository;
+use git2::{Repository, Status};
+use serde::{Deserialize, Serialize};
 use std::path::Path;
 
 pub struct GitService;
@@ -15,6 +16,17 @@ impl GitService {
             .with_context(|| format!("Failed to clone repository from {} to {:?}", url, path))
     }
 
+    /// Open the repository at `path` if one already exists there, otherwise
+    /// clone `url` into it. Makes project-bootstrap idempotent across
+    /// re-runs of initialization.
+    pub fn clone_if_missing(&self, url: &str, path: &Path) -> Result<Repository> {
+        if self.is_repository(path) {
+            self.open_repository(path)
+        } else {
+            self.clone_repository(url, path)
+        }
+    }
+
     /// Open an existing repository
     pub fn open_repository(&self, path: &Path) -> Result<Repository> {
         Repository::open(path)
@@ -58,7 +70,7 @@ impl GitService {
         .with_context(|| "Failed to create commit")
     }
 
-    /// Get repository status
+    /// Get repository status as a flat list of changed paths
     pub fn get_status(&self, repo: &Repository) -> Result<Vec<String>> {
         let statuses = repo.statuses(None)?;
         let mut files = Vec::new();
@@ -71,6 +83,102 @@ impl GitService {
 
         Ok(files)
     }
+
+    /// Get repository status bucketed into staged, modified, untracked, and
+    /// conflicted paths
+    pub fn get_structured_status(&self, repo: &Repository) -> Result<RepoStatus> {
+        let statuses = repo.statuses(None)?;
+        let mut status = RepoStatus::default();
+
+        const STAGED: Status = Status::INDEX_NEW
+            .union(Status::INDEX_MODIFIED)
+            .union(Status::INDEX_DELETED)
+            .union(Status::INDEX_RENAMED)
+            .union(Status::INDEX_TYPECHANGE);
+        const MODIFIED: Status = Status::WT_MODIFIED
+            .union(Status::WT_DELETED)
+            .union(Status::WT_RENAMED)
+            .union(Status::WT_TYPECHANGE);
+
+        for entry in statuses.iter() {
+            let Some(path) = entry.path() else {
+                continue;
+            };
+            let flags = entry.status();
+
+            if flags.intersects(Status::CONFLICTED) {
+                status.conflicted.push(path.to_string());
+            } else if flags.intersects(STAGED) {
+                status.staged.push(path.to_string());
+            } else if flags.intersects(MODIFIED) {
+                status.modified.push(path.to_string());
+            } else if flags.intersects(Status::WT_NEW) {
+                status.untracked.push(path.to_string());
+            }
+        }
+
+        Ok(status)
+    }
+
+    /// List local branch names
+    pub fn list_branches(&self, repo: &Repository) -> Result<Vec<String>> {
+        let mut branches = Vec::new();
+        for branch in repo.branches(Some(git2::BranchType::Local))? {
+            let (branch, _) = branch?;
+            if let Some(name) = branch.name()? {
+                branches.push(name.to_string());
+            }
+        }
+        Ok(branches)
+    }
+
+    /// List tag names
+    pub fn list_tags(&self, repo: &Repository) -> Result<Vec<String>> {
+        Ok(repo
+            .tag_names(None)?
+            .iter()
+            .flatten()
+            .map(|name| name.to_string())
+            .collect())
+    }
+
+    /// Walk the revwalk from HEAD and return the `limit` most recent commits
+    pub fn recent_commits(&self, repo: &Repository, limit: usize) -> Result<Vec<CommitSummary>> {
+        let mut revwalk = repo.revwalk()?;
+        revwalk.push_head()?;
+
+        let mut commits = Vec::new();
+        for oid in revwalk.take(limit) {
+            let commit = repo.find_commit(oid?)?;
+
+            commits.push(CommitSummary {
+                hash: commit.id().to_string()[..7].to_string(),
+                author: commit.author().name().unwrap_or("unknown").to_string(),
+                summary: commit.summary().unwrap_or("").to_string(),
+                timestamp: commit.time().seconds(),
+            });
+        }
+
+        Ok(commits)
+    }
+}
+
+/// Repository status bucketed by the kind of change
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RepoStatus {
+    pub staged: Vec<String>,
+    pub modified: Vec<String>,
+    pub untracked: Vec<String>,
+    pub conflicted: Vec<String>,
+}
+
+/// A single commit as shown in repository history
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CommitSummary {
+    pub hash: String,
+    pub author: String,
+    pub summary: String,
+    pub timestamp: i64,
 }
 
 impl Default for GitService {
@@ -1,8 +1,82 @@
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::Duration;
+
+/// Number of attempts `fetch_with_retry` makes before giving up on a
+/// registry request.
+const MAX_REGISTRY_ATTEMPTS: u32 = 3;
+
+/// Minimum fuzzy-match score (see `framework_match_score`) for a dependency
+/// name to be recognized as a known framework. Calibrated against
+/// `word_match_score`'s three tiers (exact word, key-within-word, one-typo
+/// word) so a single-typo match barely clears it while two or more
+/// differing characters don't.
+const FRAMEWORK_MATCH_THRESHOLD: i32 = 40;
+
+/// Curated npm package name -> canonical technology string, matched against
+/// dependency names fuzzily rather than exactly.
+const NPM_FRAMEWORK_KEYWORDS: [(&str, &str); 17] = [
+    ("react", "react"),
+    ("vue", "vue"),
+    ("angular", "angular"),
+    ("svelte", "svelte"),
+    ("next", "next"),
+    ("nuxt", "nuxt"),
+    ("express", "express"),
+    ("fastify", "fastify"),
+    ("koa", "koa"),
+    ("nest", "nestjs"),
+    ("typescript", "typescript"),
+    ("vite", "vite"),
+    ("webpack", "webpack"),
+    ("jest", "jest"),
+    ("vitest", "vitest"),
+    ("cypress", "cypress"),
+    ("playwright", "playwright"),
+];
+
+/// Maps Cargo dependency names to the canonical technology string used
+/// elsewhere in the analyzer, shared between the text-based parse and the
+/// `cargo metadata` path.
+const CARGO_FRAMEWORK_KEYWORDS: [(&str, &str); 7] = [
+    ("actix-web", "actix"),
+    ("rocket", "rocket"),
+    ("axum", "axum"),
+    ("warp", "warp"),
+    ("tokio", "tokio"),
+    ("async-std", "async-std"),
+    ("tauri", "tauri"),
+];
+
+/// Maps PyPI package names to the canonical technology string, shared
+/// between the `requirements.txt` parse and the dependency-graph walk.
+const PYTHON_FRAMEWORK_KEYWORDS: [(&str, &str); 10] = [
+    ("django", "django"),
+    ("flask", "flask"),
+    ("fastapi", "fastapi"),
+    ("tornado", "tornado"),
+    ("pyramid", "pyramid"),
+    ("pandas", "pandas"),
+    ("numpy", "numpy"),
+    ("tensorflow", "tensorflow"),
+    ("pytorch", "pytorch"),
+    ("scikit-learn", "sklearn"),
+];
+
+/// Maps Go module paths to the canonical technology string, shared between
+/// the `go.mod` parse and the dependency-graph walk.
+const GO_FRAMEWORK_KEYWORDS: [(&str, &str); 4] = [
+    ("gin-gonic/gin", "gin"),
+    ("gofiber/fiber", "fiber"),
+    ("labstack/echo", "echo"),
+    ("gorilla/mux", "gorilla"),
+];
 
 pub struct ParserService;
 
@@ -39,36 +113,15 @@ impl ParserService {
         Ok(technologies)
     }
 
-    /// Extract frameworks from dependencies
+    /// Extract frameworks from dependencies, fuzzy-matching each dependency
+    /// name against the curated keyword list so scoped packages
+    /// (`@angular/core`), monorepo subpackages
+    /// (`@nestjs/platform-express`), and typos are still recognized.
     fn extract_frameworks_from_deps(&self, deps: &serde_json::Map<String, Value>) -> Vec<String> {
         let mut frameworks = Vec::new();
 
-        let framework_map: HashMap<&str, &str> = [
-            ("react", "react"),
-            ("vue", "vue"),
-            ("angular", "angular"),
-            ("svelte", "svelte"),
-            ("next", "next"),
-            ("nuxt", "nuxt"),
-            ("express", "express"),
-            ("fastify", "fastify"),
-            ("koa", "koa"),
-            ("nest", "nestjs"),
-            ("@nestjs/core", "nestjs"),
-            ("typescript", "typescript"),
-            ("vite", "vite"),
-            ("webpack", "webpack"),
-            ("jest", "jest"),
-            ("vitest", "vitest"),
-            ("cypress", "cypress"),
-            ("playwright", "playwright"),
-        ]
-        .iter()
-        .cloned()
-        .collect();
-
-        for (dep_name, _) in deps {
-            if let Some(framework) = framework_map.get(dep_name.as_str()) {
+        for dep_name in deps.keys() {
+            if let Some(framework) = best_framework_match(&NPM_FRAMEWORK_KEYWORDS, dep_name) {
                 if !frameworks.contains(&framework.to_string()) {
                     frameworks.push(framework.to_string());
                 }
@@ -85,25 +138,14 @@ impl ParserService {
 
         let mut technologies = vec!["python".to_string()];
 
-        let framework_keywords = [
-            ("django", "django"),
-            ("flask", "flask"),
-            ("fastapi", "fastapi"),
-            ("tornado", "tornado"),
-            ("pyramid", "pyramid"),
-            ("pandas", "pandas"),
-            ("numpy", "numpy"),
-            ("tensorflow", "tensorflow"),
-            ("pytorch", "pytorch"),
-            ("scikit-learn", "sklearn"),
-        ];
-
         for line in content.lines() {
-            let package = line.split("==").next().unwrap_or("").trim().to_lowercase();
-            for (keyword, framework) in &framework_keywords {
-                if package.contains(keyword) {
-                    technologies.push(framework.to_string());
-                }
+            let package = line.split("==").next().unwrap_or("").trim();
+            if package.is_empty() {
+                continue;
+            }
+
+            if let Some(framework) = best_framework_match(&PYTHON_FRAMEWORK_KEYWORDS, package) {
+                technologies.push(framework.to_string());
             }
         }
 
@@ -119,18 +161,8 @@ impl ParserService {
         let mut technologies = vec!["rust".to_string()];
 
         if let Some(deps) = cargo.get("dependencies").and_then(|v| v.as_table()) {
-            let framework_keywords = [
-                ("actix-web", "actix"),
-                ("rocket", "rocket"),
-                ("axum", "axum"),
-                ("warp", "warp"),
-                ("tokio", "tokio"),
-                ("async-std", "async-std"),
-                ("tauri", "tauri"),
-            ];
-
-            for (keyword, framework) in &framework_keywords {
-                if deps.contains_key(*keyword) {
+            for name in deps.keys() {
+                if let Some(framework) = best_framework_match(&CARGO_FRAMEWORK_KEYWORDS, name) {
                     technologies.push(framework.to_string());
                 }
             }
@@ -139,6 +171,70 @@ impl ParserService {
         Ok(technologies)
     }
 
+    /// Parse a Cargo project via `cargo metadata`, falling back to the plain
+    /// text parse of `Cargo.toml` when `cargo` is unavailable or the command
+    /// fails, so analysis never hard-errors on a Rust project.
+    pub fn parse_cargo_workspace(&self, manifest_path: &Path) -> Result<CargoWorkspaceInfo> {
+        match self.run_cargo_metadata(manifest_path) {
+            Ok(info) => Ok(info),
+            Err(_) => Ok(CargoWorkspaceInfo {
+                technologies: self.parse_cargo_toml(manifest_path)?,
+                edition: None,
+                workspace_members: Vec::new(),
+            }),
+        }
+    }
+
+    fn run_cargo_metadata(&self, manifest_path: &Path) -> Result<CargoWorkspaceInfo> {
+        let output = Command::new("cargo")
+            .args(["metadata", "--format-version", "1", "--no-deps"])
+            .arg("--manifest-path")
+            .arg(manifest_path)
+            .output()
+            .context("Failed to run cargo metadata")?;
+
+        if !output.status.success() {
+            anyhow::bail!("cargo metadata exited with status {}", output.status);
+        }
+
+        let metadata: CargoMetadataJson = serde_json::from_slice(&output.stdout)
+            .context("Failed to parse cargo metadata output")?;
+
+        // `cargo metadata` reports each package's manifest_path as an
+        // absolute, canonicalized path, while `manifest_path` here is
+        // whatever the caller passed in (often relative, sometimes not
+        // canonical) — compare canonicalized forms rather than the raw
+        // paths so the root package is still found.
+        let canonical_manifest_path = fs::canonicalize(manifest_path).unwrap_or_else(|_| manifest_path.to_path_buf());
+        let root_package = metadata.packages.iter().find(|package| {
+            fs::canonicalize(&package.manifest_path)
+                .map(|path| path == canonical_manifest_path)
+                .unwrap_or(false)
+        });
+
+        let mut technologies = vec!["rust".to_string()];
+        if let Some(package) = root_package {
+            for dep in &package.dependencies {
+                if let Some(framework) = best_framework_match(&CARGO_FRAMEWORK_KEYWORDS, &dep.name) {
+                    technologies.push(framework.to_string());
+                }
+            }
+        }
+
+        let workspace_members = metadata
+            .packages
+            .iter()
+            .filter(|package| metadata.workspace_members.contains(&package.id))
+            .filter_map(|package| Path::new(&package.manifest_path).parent().map(Path::to_path_buf))
+            .collect();
+
+        Ok(CargoWorkspaceInfo {
+            technologies,
+            edition: root_package.map(|package| package.edition.clone()),
+            workspace_members,
+        })
+    }
+
     /// Parse go.mod and extract Go frameworks
     pub fn parse_go_mod(&self, path: &Path) -> Result<Vec<String>> {
         let content = fs::read_to_string(path)
@@ -146,22 +242,426 @@ impl ParserService {
 
         let mut technologies = vec!["go".to_string()];
 
-        let framework_keywords = [
-            ("gin-gonic/gin", "gin"),
-            ("gofiber/fiber", "fiber"),
-            ("labstack/echo", "echo"),
-            ("gorilla/mux", "gorilla"),
-        ];
+        for line in content.lines() {
+            let line = line.trim().trim_start_matches("require ").trim();
+            let module = line.split_whitespace().next().unwrap_or("");
+            if module.is_empty() {
+                continue;
+            }
+
+            if let Some(framework) = best_framework_match(&GO_FRAMEWORK_KEYWORDS, module) {
+                technologies.push(framework.to_string());
+            }
+        }
+
+        Ok(technologies)
+    }
+
+    /// Check pinned dependency versions in `manifest` against their
+    /// upstream registry and report which are outdated. Registry requests
+    /// are retried with exponential backoff, and a failure on one
+    /// dependency (not found, or network/parse failure) doesn't abort the
+    /// rest: the reachable subset is still returned, with the failures
+    /// reported separately so a caller can tell a missing package from an
+    /// unreachable registry instead of having it silently dropped.
+    pub async fn check_outdated(&self, manifest: &Path) -> Result<DependencyCheckReport> {
+        let ecosystem = manifest
+            .file_name()
+            .and_then(|name| name.to_str())
+            .context("Manifest path has no file name")?;
+
+        let specs = match ecosystem {
+            "package.json" => self.read_npm_specs(manifest)?,
+            "requirements.txt" => self.read_pypi_specs(manifest)?,
+            "Cargo.toml" => self.read_cargo_specs(manifest)?,
+            "go.mod" => self.read_go_specs(manifest)?,
+            other => anyhow::bail!("Unsupported manifest for outdated check: {}", other),
+        };
+
+        let client = reqwest::Client::new();
+        let mut outdated = Vec::new();
+        let mut errors = Vec::new();
+
+        for (name, pinned) in specs {
+            let latest = match self.latest_version(&client, ecosystem, &name).await {
+                Ok(version) => version,
+                Err(err) => {
+                    errors.push(DependencyCheckError {
+                        kind: err.kind(),
+                        message: err.to_string(),
+                        name,
+                    });
+                    continue;
+                }
+            };
+
+            if let Some(severity) = version_severity(&pinned, &latest) {
+                outdated.push(OutdatedDep {
+                    name,
+                    pinned,
+                    latest,
+                    severity,
+                });
+            }
+        }
 
+        Ok(DependencyCheckReport { outdated, errors })
+    }
+
+    async fn latest_version(
+        &self,
+        client: &reqwest::Client,
+        ecosystem: &str,
+        name: &str,
+    ) -> Result<String, RegistryError> {
+        match ecosystem {
+            "package.json" => self.npm_latest(client, name).await,
+            "requirements.txt" => self.pypi_latest(client, name).await,
+            "Cargo.toml" => self.crates_latest(client, name).await,
+            "go.mod" => self.go_proxy_latest(client, name).await,
+            other => Err(RegistryError::Unreachable(format!(
+                "no registry configured for {}",
+                other
+            ))),
+        }
+    }
+
+    async fn npm_latest(&self, client: &reqwest::Client, name: &str) -> Result<String, RegistryError> {
+        let url = format!("https://registry.npmjs.org/{}", encode_npm_package(name));
+        let body = fetch_with_retry(client, &url, name).await?;
+
+        body.get("dist-tags")
+            .and_then(|tags| tags.get("latest"))
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string())
+            .ok_or_else(|| RegistryError::Unreachable(format!("missing dist-tags.latest for {}", name)))
+    }
+
+    async fn pypi_latest(&self, client: &reqwest::Client, name: &str) -> Result<String, RegistryError> {
+        let url = format!("https://pypi.org/pypi/{}/json", name);
+        let body = fetch_with_retry(client, &url, name).await?;
+
+        body.get("info")
+            .and_then(|info| info.get("version"))
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string())
+            .ok_or_else(|| RegistryError::Unreachable(format!("missing info.version for {}", name)))
+    }
+
+    async fn crates_latest(&self, client: &reqwest::Client, name: &str) -> Result<String, RegistryError> {
+        let url = format!("https://crates.io/api/v1/crates/{}", name);
+        let body = fetch_with_retry(client, &url, name).await?;
+
+        body.get("crate")
+            .and_then(|krate| krate.get("max_stable_version"))
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string())
+            .ok_or_else(|| {
+                RegistryError::Unreachable(format!("missing crate.max_stable_version for {}", name))
+            })
+    }
+
+    async fn go_proxy_latest(&self, client: &reqwest::Client, name: &str) -> Result<String, RegistryError> {
+        let url = format!("https://proxy.golang.org/{}/@latest", name);
+        let body = fetch_with_retry(client, &url, name).await?;
+
+        body.get("Version")
+            .and_then(|v| v.as_str())
+            .map(|v| v.to_string())
+            .ok_or_else(|| RegistryError::Unreachable(format!("missing Version for {}", name)))
+    }
+
+    /// Extract (name, pinned-version) pairs from package.json's
+    /// `dependencies`/`devDependencies`.
+    fn read_npm_specs(&self, path: &Path) -> Result<Vec<(String, String)>> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read package.json at {:?}", path))?;
+        let package: Value = serde_json::from_str(&content)?;
+
+        let mut specs = Vec::new();
+        for key in ["dependencies", "devDependencies"] {
+            if let Some(deps) = package.get(key).and_then(|v| v.as_object()) {
+                for (name, version) in deps {
+                    if let Some(version) = version.as_str() {
+                        specs.push((name.clone(), version.to_string()));
+                    }
+                }
+            }
+        }
+
+        Ok(specs)
+    }
+
+    /// Extract (name, pinned-version) pairs from `==`-pinned lines of
+    /// requirements.txt; unpinned lines have nothing to compare against.
+    fn read_pypi_specs(&self, path: &Path) -> Result<Vec<(String, String)>> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read requirements.txt at {:?}", path))?;
+
+        let mut specs = Vec::new();
         for line in content.lines() {
-            for (keyword, framework) in &framework_keywords {
-                if line.contains(keyword) {
-                    technologies.push(framework.to_string());
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((name, version)) = line.split_once("==") {
+                specs.push((name.trim().to_string(), version.trim().to_string()));
+            }
+        }
+
+        Ok(specs)
+    }
+
+    /// Extract (name, pinned-version) pairs from Cargo.toml's
+    /// `[dependencies]`, handling both `dep = "1.0"` and
+    /// `dep = { version = "1.0" }` forms.
+    fn read_cargo_specs(&self, path: &Path) -> Result<Vec<(String, String)>> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read Cargo.toml at {:?}", path))?;
+        let cargo: toml::Value = toml::from_str(&content)?;
+
+        let mut specs = Vec::new();
+        if let Some(deps) = cargo.get("dependencies").and_then(|v| v.as_table()) {
+            for (name, value) in deps {
+                let version = match value {
+                    toml::Value::String(version) => Some(version.clone()),
+                    toml::Value::Table(table) => table
+                        .get("version")
+                        .and_then(|v| v.as_str())
+                        .map(|v| v.to_string()),
+                    _ => None,
+                };
+
+                if let Some(version) = version {
+                    specs.push((name.clone(), version));
                 }
             }
         }
 
-        Ok(technologies)
+        Ok(specs)
+    }
+
+    /// Resolve every technology reachable through `manifest`'s full
+    /// dependency graph (not just its top-level dependencies), using the
+    /// ecosystem's lockfile to walk transitive dependencies, and recording
+    /// which direct dependency pulled each technology in. Falls back to a
+    /// direct-only graph when no lockfile is present, so the manifest's own
+    /// dependencies are still covered; a lockfile that is present but fails
+    /// to parse is returned as an error.
+    pub fn analyze_dependency_graph(&self, manifest: &Path) -> Result<Vec<TechnologyOrigin>> {
+        let ecosystem = manifest
+            .file_name()
+            .and_then(|name| name.to_str())
+            .context("Manifest path has no file name")?;
+
+        let (graph, keywords): (DependencyGraph, &[(&str, &str)]) = match ecosystem {
+            "package.json" => (
+                self.read_npm_graph(manifest)?,
+                &NPM_FRAMEWORK_KEYWORDS as &[(&str, &str)],
+            ),
+            "Cargo.toml" => (
+                self.read_cargo_graph(manifest)?,
+                &CARGO_FRAMEWORK_KEYWORDS as &[(&str, &str)],
+            ),
+            "requirements.txt" => (
+                self.read_pypi_graph(manifest)?,
+                &PYTHON_FRAMEWORK_KEYWORDS as &[(&str, &str)],
+            ),
+            "go.mod" => (
+                self.read_go_graph(manifest)?,
+                &GO_FRAMEWORK_KEYWORDS as &[(&str, &str)],
+            ),
+            other => anyhow::bail!("Unsupported manifest for dependency graph: {}", other),
+        };
+
+        Ok(resolve_technology_origins(&graph, keywords))
+    }
+
+    /// Build the npm dependency graph from `package-lock.json` next to
+    /// `manifest`, supporting both the nested v1 `dependencies` layout and
+    /// the flat v2/v3 `packages` layout. Falls back to a direct-only graph
+    /// (package.json's own dependencies, no transitive edges) when no
+    /// lockfile is present.
+    fn read_npm_graph(&self, manifest: &Path) -> Result<DependencyGraph> {
+        let direct: Vec<String> = self
+            .read_npm_specs(manifest)?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+
+        let lockfile_path = manifest
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join("package-lock.json");
+
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+        if let Ok(content) = fs::read_to_string(&lockfile_path) {
+            let lockfile: Value = serde_json::from_str(&content)
+                .with_context(|| format!("Failed to parse {:?}", lockfile_path))?;
+
+            if let Some(packages) = lockfile.get("packages").and_then(|v| v.as_object()) {
+                // v2/v3 lockfile: keys are "" (root) or paths like
+                // "node_modules/scope/name"; the package name is whatever
+                // follows the last "node_modules/" segment.
+                for (key, package) in packages {
+                    if key.is_empty() {
+                        continue;
+                    }
+                    let Some(name) = key.rsplit("node_modules/").next() else {
+                        continue;
+                    };
+                    let deps = package
+                        .get("dependencies")
+                        .and_then(|v| v.as_object())
+                        .map(|deps| deps.keys().cloned().collect())
+                        .unwrap_or_default();
+                    children.insert(name.to_string(), deps);
+                }
+            } else if let Some(deps) = lockfile.get("dependencies").and_then(|v| v.as_object()) {
+                collect_npm_v1_dependencies(deps, &mut children);
+            }
+        }
+
+        Ok(DependencyGraph { direct, children })
+    }
+
+    /// Build the Cargo dependency graph from `Cargo.lock` next to
+    /// `manifest`. Falls back to a direct-only graph when no lockfile is
+    /// present.
+    fn read_cargo_graph(&self, manifest: &Path) -> Result<DependencyGraph> {
+        let direct: Vec<String> = self
+            .read_cargo_specs(manifest)?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+
+        let lockfile_path = manifest
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join("Cargo.lock");
+
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+        if let Ok(content) = fs::read_to_string(&lockfile_path) {
+            let lockfile: toml::Value = toml::from_str(&content)
+                .with_context(|| format!("Failed to parse {:?}", lockfile_path))?;
+
+            if let Some(packages) = lockfile.get("package").and_then(|v| v.as_array()) {
+                for package in packages {
+                    let Some(name) = package.get("name").and_then(|v| v.as_str()) else {
+                        continue;
+                    };
+                    let deps = package
+                        .get("dependencies")
+                        .and_then(|v| v.as_array())
+                        .map(|deps| {
+                            deps.iter()
+                                .filter_map(|dep| dep.as_str())
+                                .map(|dep| dep.split_whitespace().next().unwrap_or(dep).to_string())
+                                .collect()
+                        })
+                        .unwrap_or_default();
+                    children.insert(name.to_string(), deps);
+                }
+            }
+        }
+
+        Ok(DependencyGraph { direct, children })
+    }
+
+    /// Build the Python dependency graph from `poetry.lock` next to
+    /// `manifest`. Falls back to a direct-only graph (no transitive edges)
+    /// when no lockfile is present, since plain `requirements.txt` doesn't
+    /// record transitive relations on its own.
+    fn read_pypi_graph(&self, manifest: &Path) -> Result<DependencyGraph> {
+        let direct: Vec<String> = self
+            .read_pypi_specs(manifest)?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+
+        let lockfile_path = manifest
+            .parent()
+            .unwrap_or_else(|| Path::new("."))
+            .join("poetry.lock");
+
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+        if let Ok(content) = fs::read_to_string(&lockfile_path) {
+            let lockfile: toml::Value = toml::from_str(&content)
+                .with_context(|| format!("Failed to parse {:?}", lockfile_path))?;
+
+            if let Some(packages) = lockfile.get("package").and_then(|v| v.as_array()) {
+                for package in packages {
+                    let Some(name) = package.get("name").and_then(|v| v.as_str()) else {
+                        continue;
+                    };
+                    let deps = package
+                        .get("dependencies")
+                        .and_then(|v| v.as_table())
+                        .map(|deps| deps.keys().cloned().collect())
+                        .unwrap_or_default();
+                    children.insert(name.to_lowercase(), deps);
+                }
+            }
+        }
+
+        Ok(DependencyGraph { direct, children })
+    }
+
+    /// Build the Go dependency graph via `go mod graph`, which prints the
+    /// full module requirement graph as `module@version module@version`
+    /// edge pairs. Falls back to a direct-only graph (go.mod's own
+    /// `require`s, no transitive edges) when `go` is unavailable or the
+    /// command fails.
+    fn read_go_graph(&self, manifest: &Path) -> Result<DependencyGraph> {
+        let direct: Vec<String> = self
+            .read_go_specs(manifest)?
+            .into_iter()
+            .map(|(name, _)| name)
+            .collect();
+
+        let mut children: HashMap<String, Vec<String>> = HashMap::new();
+
+        if let Ok(output) = Command::new("go")
+            .arg("mod")
+            .arg("graph")
+            .current_dir(manifest.parent().unwrap_or_else(|| Path::new(".")))
+            .output()
+        {
+            if output.status.success() {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                for line in stdout.lines() {
+                    let mut parts = line.split_whitespace();
+                    let (Some(parent), Some(child)) = (parts.next(), parts.next()) else {
+                        continue;
+                    };
+                    let parent = parent.split('@').next().unwrap_or(parent).to_string();
+                    let child = child.split('@').next().unwrap_or(child).to_string();
+                    children.entry(parent).or_default().push(child);
+                }
+            }
+        }
+
+        Ok(DependencyGraph { direct, children })
+    }
+
+    /// Extract (module, pinned-version) pairs from `require` lines of
+    /// go.mod, in both single-line and block form.
+    fn read_go_specs(&self, path: &Path) -> Result<Vec<(String, String)>> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read go.mod at {:?}", path))?;
+
+        let mut specs = Vec::new();
+        for line in content.lines() {
+            let line = line.trim().trim_start_matches("require ").trim();
+            let mut parts = line.split_whitespace();
+            if let (Some(module), Some(version)) = (parts.next(), parts.next()) {
+                if version.starts_with('v') && version.contains('.') {
+                    specs.push((module.to_string(), version.to_string()));
+                }
+            }
+        }
+
+        Ok(specs)
     }
 }
 
@@ -170,3 +670,522 @@ impl Default for ParserService {
         Self::new()
     }
 }
+
+/// Result of analyzing a Rust project via `cargo metadata`: detected
+/// technologies, the declared edition, and any workspace member directories.
+#[derive(Debug, Clone, Default)]
+pub struct CargoWorkspaceInfo {
+    pub technologies: Vec<String>,
+    pub edition: Option<String>,
+    pub workspace_members: Vec<PathBuf>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoMetadataJson {
+    packages: Vec<CargoPackageJson>,
+    workspace_members: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoPackageJson {
+    id: String,
+    manifest_path: String,
+    #[serde(default)]
+    edition: String,
+    #[serde(default)]
+    dependencies: Vec<CargoDependencyJson>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CargoDependencyJson {
+    name: String,
+}
+
+/// A direct-dependency name mapped to the names of the dependencies it
+/// pulls in, as read from an ecosystem's lockfile. `direct` lists the
+/// manifest's own top-level dependencies (the graph's roots); `children`
+/// may also cover packages no lockfile entry was found for, in which case
+/// they're treated as leaves.
+#[derive(Debug, Clone, Default)]
+struct DependencyGraph {
+    direct: Vec<String>,
+    children: HashMap<String, Vec<String>>,
+}
+
+/// A technology detected via the dependency graph, together with the
+/// direct (manifest-level) dependency that pulled it in — directly, if
+/// that dependency itself matched a known framework, or transitively if
+/// the match only showed up further down the lockfile's dependency tree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TechnologyOrigin {
+    pub technology: String,
+    pub introduced_by: String,
+    pub transitive: bool,
+}
+
+/// Walk the dependency graph from each direct dependency (breadth-first, so
+/// the direct dependency itself is checked before anything it pulls in),
+/// recording the first direct dependency that introduces each technology.
+fn resolve_technology_origins(
+    graph: &DependencyGraph,
+    keywords: &[(&str, &str)],
+) -> Vec<TechnologyOrigin> {
+    let mut origins = Vec::new();
+    let mut seen_technologies: HashSet<String> = HashSet::new();
+
+    for direct in &graph.direct {
+        let mut queue: VecDeque<&str> = VecDeque::new();
+        let mut visited: HashSet<&str> = HashSet::new();
+        queue.push_back(direct);
+        visited.insert(direct);
+
+        while let Some(name) = queue.pop_front() {
+            if let Some(framework) = best_framework_match(keywords, name) {
+                if seen_technologies.insert(framework.to_string()) {
+                    origins.push(TechnologyOrigin {
+                        technology: framework.to_string(),
+                        introduced_by: direct.clone(),
+                        transitive: name != direct,
+                    });
+                }
+            }
+
+            for child in graph.children.get(name).into_iter().flatten() {
+                if visited.insert(child) {
+                    queue.push_back(child);
+                }
+            }
+        }
+    }
+
+    origins
+}
+
+#[cfg(test)]
+mod technology_origin_tests {
+    use super::*;
+
+    fn graph(direct: &[&str], children: &[(&str, &[&str])]) -> DependencyGraph {
+        DependencyGraph {
+            direct: direct.iter().map(|s| s.to_string()).collect(),
+            children: children
+                .iter()
+                .map(|(name, deps)| {
+                    (
+                        name.to_string(),
+                        deps.iter().map(|d| d.to_string()).collect(),
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn direct_match_is_not_transitive() {
+        let graph = graph(&["react"], &[]);
+        let origins = resolve_technology_origins(&graph, &NPM_FRAMEWORK_KEYWORDS);
+
+        assert_eq!(
+            origins,
+            vec![TechnologyOrigin {
+                technology: "react".to_string(),
+                introduced_by: "react".to_string(),
+                transitive: false,
+            }]
+        );
+    }
+
+    #[test]
+    fn transitive_match_is_attributed_to_the_direct_dependency() {
+        let graph = graph(
+            &["some-meta-framework"],
+            &[("some-meta-framework", &["react-dom"])],
+        );
+        let origins = resolve_technology_origins(&graph, &NPM_FRAMEWORK_KEYWORDS);
+
+        assert_eq!(
+            origins,
+            vec![TechnologyOrigin {
+                technology: "react".to_string(),
+                introduced_by: "some-meta-framework".to_string(),
+                transitive: true,
+            }]
+        );
+    }
+
+    #[test]
+    fn same_technology_is_only_recorded_once() {
+        let graph = graph(
+            &["react", "react-redux"],
+            &[("react-redux", &["react-dom"])],
+        );
+        let origins = resolve_technology_origins(&graph, &NPM_FRAMEWORK_KEYWORDS);
+
+        assert_eq!(origins.len(), 1);
+        assert_eq!(origins[0].introduced_by, "react");
+    }
+
+    #[test]
+    fn cyclical_children_do_not_cause_infinite_loop() {
+        let graph = graph(&["a"], &[("a", &["b"]), ("b", &["a"])]);
+        let origins = resolve_technology_origins(&graph, &NPM_FRAMEWORK_KEYWORDS);
+
+        assert!(origins.is_empty());
+    }
+}
+
+/// Recursively walk npm lockfile v1's nested `dependencies` object, where
+/// each entry may itself carry a nested `dependencies` object for its own
+/// children.
+fn collect_npm_v1_dependencies(
+    deps: &serde_json::Map<String, Value>,
+    children: &mut HashMap<String, Vec<String>>,
+) {
+    for (name, value) in deps {
+        let direct_children = value
+            .get("requires")
+            .and_then(|v| v.as_object())
+            .map(|requires| requires.keys().cloned().collect())
+            .unwrap_or_default();
+        children.insert(name.clone(), direct_children);
+
+        if let Some(nested) = value.get("dependencies").and_then(|v| v.as_object()) {
+            collect_npm_v1_dependencies(nested, children);
+        }
+    }
+}
+
+/// A dependency whose pinned version is behind the registry's latest stable
+/// release.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OutdatedDep {
+    pub name: String,
+    pub pinned: String,
+    pub latest: String,
+    pub severity: VersionSeverity,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VersionSeverity {
+    Major,
+    Minor,
+    Patch,
+}
+
+/// Result of `check_outdated`: the dependencies found to be outdated, plus
+/// the ones that couldn't be checked at all, so a caller can distinguish
+/// "up to date" from "we don't know".
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyCheckReport {
+    pub outdated: Vec<OutdatedDep>,
+    pub errors: Vec<DependencyCheckError>,
+}
+
+/// A dependency `check_outdated` couldn't resolve against its registry,
+/// with the error kind preserved so a caller can tell a missing package
+/// from a network/parse failure rather than just seeing it vanish from the
+/// result set.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DependencyCheckError {
+    pub name: String,
+    pub kind: RegistryErrorKind,
+    pub message: String,
+}
+
+/// Distinguishes a package that doesn't exist upstream from one the
+/// registry request itself failed to reach or parse, so callers can decide
+/// whether to retry or drop it from a partial result set.
+#[derive(Debug)]
+enum RegistryError {
+    NotFound(String),
+    Unreachable(String),
+}
+
+impl RegistryError {
+    fn kind(&self) -> RegistryErrorKind {
+        match self {
+            RegistryError::NotFound(_) => RegistryErrorKind::NotFound,
+            RegistryError::Unreachable(_) => RegistryErrorKind::Unreachable,
+        }
+    }
+}
+
+/// Serializable counterpart of `RegistryError`'s variants, for reporting a
+/// per-dependency failure kind to callers outside this module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum RegistryErrorKind {
+    NotFound,
+    Unreachable,
+}
+
+impl fmt::Display for RegistryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::NotFound(package) => write!(f, "package '{}' not found", package),
+            RegistryError::Unreachable(reason) => write!(f, "registry unreachable: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {}
+
+/// GET `url` with up to `MAX_REGISTRY_ATTEMPTS`, backing off exponentially
+/// between attempts. A 404 is treated as `NotFound` immediately (retrying
+/// won't make the package exist); anything else that keeps failing becomes
+/// `Unreachable` once attempts are exhausted.
+async fn fetch_with_retry(
+    client: &reqwest::Client,
+    url: &str,
+    package: &str,
+) -> Result<Value, RegistryError> {
+    let mut attempt = 0;
+
+    loop {
+        attempt += 1;
+
+        match client.get(url).send().await {
+            Ok(response) if response.status() == reqwest::StatusCode::NOT_FOUND => {
+                return Err(RegistryError::NotFound(package.to_string()));
+            }
+            Ok(response) if response.status().is_success() => {
+                return response
+                    .json::<Value>()
+                    .await
+                    .map_err(|e| RegistryError::Unreachable(e.to_string()));
+            }
+            Ok(response) if attempt >= MAX_REGISTRY_ATTEMPTS => {
+                return Err(RegistryError::Unreachable(format!(
+                    "status {}",
+                    response.status()
+                )));
+            }
+            Err(e) if attempt >= MAX_REGISTRY_ATTEMPTS => {
+                return Err(RegistryError::Unreachable(e.to_string()));
+            }
+            _ => {}
+        }
+
+        let backoff = Duration::from_millis(200 * 2u64.pow(attempt - 1));
+        tokio::time::sleep(backoff).await;
+    }
+}
+
+/// Pick the best-scoring framework for `dep_name` out of `candidates`,
+/// requiring at least `FRAMEWORK_MATCH_THRESHOLD` so an unrelated dependency
+/// doesn't get matched to the nearest keyword by coincidence. Ties are
+/// broken in favor of the shorter key, since a shorter key matching equally
+/// well is the more specific/confident signal.
+fn best_framework_match<'a>(
+    candidates: &[(&'a str, &'a str)],
+    dep_name: &str,
+) -> Option<&'a str> {
+    let dep_name_lower = dep_name.to_lowercase();
+
+    candidates
+        .iter()
+        .filter_map(|(key, framework)| {
+            framework_match_score(key, &dep_name_lower).map(|score| (*key, *framework, score))
+        })
+        .filter(|(_, _, score)| *score >= FRAMEWORK_MATCH_THRESHOLD)
+        .max_by(|(key_a, _, score_a), (key_b, _, score_b)| {
+            score_a.cmp(score_b).then_with(|| key_b.len().cmp(&key_a.len()))
+        })
+        .map(|(_, framework, _)| framework)
+}
+
+/// Score `key` against `dep_name_lower` by splitting it into
+/// `/`-, `-`-, `@`-, and `.`-delimited words (so `@nestjs/platform-express`
+/// is considered as `["nestjs", "platform", "express"]`) and scoring `key`
+/// against each word independently, keeping the best. Working per word
+/// keeps the one-typo tier to a single word of a longer name; a key found
+/// inside a longer word still matches at the key-within-word tier, so
+/// `invite` matches `vite` and `koala` matches `koa`. A key that itself
+/// contains a separator (`actix-web`, `gin-gonic/gin`) can't equal or sit
+/// inside any single word, so it's also checked against the whole name.
+/// Returns `None` if nothing scores high enough to mean anything.
+fn framework_match_score(key: &str, dep_name_lower: &str) -> Option<i32> {
+    let key = key.to_lowercase();
+
+    let whole_name = if dep_name_lower == key {
+        Some(100)
+    } else if dep_name_lower.contains(key.as_str()) {
+        Some(60)
+    } else {
+        None
+    };
+
+    let best_word = dep_name_lower
+        .split(|c| matches!(c, '/' | '-' | '@' | '.'))
+        .filter(|word| !word.is_empty())
+        .filter_map(|word| word_match_score(&key, word))
+        .max();
+
+    whole_name.max(best_word)
+}
+
+/// Score `key` against a single boundary-delimited `word` of a dependency
+/// name. An exact match is the strongest signal; `key` appearing as a
+/// contiguous run inside a longer word (`expressjs`, `vuejs`, `nestjs`)
+/// is next; a single-character typo of the *entire* word (`reakt` for
+/// `react`, `expres` for `express`) is the weakest signal that still
+/// counts, scoped to one word at a time so it can't fire on an unrelated
+/// dependency the way an unscoped edit-distance check would.
+fn word_match_score(key: &str, word: &str) -> Option<i32> {
+    if word == key {
+        return Some(100);
+    }
+
+    if word.contains(key) {
+        return Some(60);
+    }
+
+    within_one_edit(key, word).then_some(40)
+}
+
+/// Whether `a` and `b` differ by at most one single-character edit
+/// (insertion, deletion, or substitution), e.g. `react`/`reakt` or
+/// `express`/`expres`. Checked directly with a two-pointer scan rather
+/// than a full Levenshtein DP, since callers only ever need "at most
+/// one", not the exact distance.
+fn within_one_edit(a: &str, b: &str) -> bool {
+    let a: Vec<char> = a.chars().collect();
+    let b: Vec<char> = b.chars().collect();
+
+    let (shorter, longer) = if a.len() <= b.len() { (&a, &b) } else { (&b, &a) };
+    if longer.len() - shorter.len() > 1 {
+        return false;
+    }
+
+    let mut i = 0;
+    let mut j = 0;
+    let mut edits = 0;
+
+    while i < shorter.len() && j < longer.len() {
+        if shorter[i] == longer[j] {
+            i += 1;
+            j += 1;
+            continue;
+        }
+
+        edits += 1;
+        if edits > 1 {
+            return false;
+        }
+
+        if shorter.len() == longer.len() {
+            // Substitution: both sides advance past the mismatch.
+            i += 1;
+            j += 1;
+        } else {
+            // Insertion/deletion: only the longer side skips the extra char.
+            j += 1;
+        }
+    }
+
+    edits += (longer.len() - j) + (shorter.len() - i);
+    edits <= 1
+}
+
+/// npm's registry expects a scoped package's `/` encoded but keeps the `@`
+/// literal, e.g. `@angular/core` -> `@angular%2fcore`.
+fn encode_npm_package(name: &str) -> String {
+    match name.strip_prefix('@') {
+        Some(rest) => format!("@{}", rest.replacen('/', "%2f", 1)),
+        None => name.to_string(),
+    }
+}
+
+/// Compare a pinned version spec (which may carry a `^`/`~`/`>=` prefix)
+/// against the registry's latest version, returning how far behind it is.
+fn version_severity(pinned: &str, latest: &str) -> Option<VersionSeverity> {
+    let pinned = pinned.trim_start_matches(|c: char| !c.is_ascii_digit());
+    let (pinned_major, pinned_minor, pinned_patch) = parse_semver(pinned)?;
+    let (latest_major, latest_minor, latest_patch) = parse_semver(latest)?;
+
+    if pinned_major < latest_major {
+        Some(VersionSeverity::Major)
+    } else if pinned_major == latest_major && pinned_minor < latest_minor {
+        Some(VersionSeverity::Minor)
+    } else if pinned_major == latest_major && pinned_minor == latest_minor && pinned_patch < latest_patch {
+        Some(VersionSeverity::Patch)
+    } else {
+        None
+    }
+}
+
+/// Parse the leading `major.minor.patch` out of a version string, ignoring
+/// any pre-release/build metadata suffix.
+fn parse_semver(version: &str) -> Option<(u64, u64, u64)> {
+    let version = version.trim_start_matches('v');
+    let mut parts = version.splitn(3, '.').map(|part| {
+        part.chars()
+            .take_while(|c| c.is_ascii_digit())
+            .collect::<String>()
+            .parse::<u64>()
+    });
+
+    let major = parts.next()?.ok()?;
+    let minor = parts.next().and_then(|r| r.ok()).unwrap_or(0);
+    let patch = parts.next().and_then(|r| r.ok()).unwrap_or(0);
+
+    Some((major, minor, patch))
+}
+
+#[cfg(test)]
+mod semver_tests {
+    use super::*;
+
+    #[test]
+    fn parse_semver_plain() {
+        assert_eq!(parse_semver("1.2.3"), Some((1, 2, 3)));
+    }
+
+    #[test]
+    fn parse_semver_strips_leading_v() {
+        assert_eq!(parse_semver("v1.2.3"), Some((1, 2, 3)));
+    }
+
+    #[test]
+    fn parse_semver_ignores_prerelease_and_build_metadata() {
+        assert_eq!(parse_semver("1.2.3-beta.1+build5"), Some((1, 2, 3)));
+    }
+
+    #[test]
+    fn parse_semver_defaults_missing_components_to_zero() {
+        assert_eq!(parse_semver("1"), Some((1, 0, 0)));
+        assert_eq!(parse_semver("1.2"), Some((1, 2, 0)));
+    }
+
+    #[test]
+    fn parse_semver_rejects_non_numeric_major() {
+        assert_eq!(parse_semver("latest"), None);
+    }
+
+    #[test]
+    fn version_severity_major_outranks_minor_and_patch() {
+        assert_eq!(version_severity("1.0.0", "2.0.0"), Some(VersionSeverity::Major));
+    }
+
+    #[test]
+    fn version_severity_minor_when_major_matches() {
+        assert_eq!(version_severity("1.0.0", "1.1.0"), Some(VersionSeverity::Minor));
+    }
+
+    #[test]
+    fn version_severity_patch_when_major_and_minor_match() {
+        assert_eq!(version_severity("1.0.0", "1.0.1"), Some(VersionSeverity::Patch));
+    }
+
+    #[test]
+    fn version_severity_none_when_pinned_is_current_or_ahead() {
+        assert_eq!(version_severity("1.0.0", "1.0.0"), None);
+        assert_eq!(version_severity("2.0.0", "1.0.0"), None);
+    }
+
+    #[test]
+    fn version_severity_strips_pinned_range_prefix() {
+        assert_eq!(version_severity("^1.0.0", "2.0.0"), Some(VersionSeverity::Major));
+        assert_eq!(version_severity("~1.0.0", "1.1.0"), Some(VersionSeverity::Minor));
+        assert_eq!(version_severity(">=1.0.0", "1.0.1"), Some(VersionSeverity::Patch));
+    }
+}